@@ -1,4 +1,4 @@
-use rchess::{ChessGame, GameResult};
+use rchess::{ChessGame, GameOverError, GameResult, MoveOutcome};
 use crate::input_getter::InputGetter;
 
 pub struct GameController {
@@ -25,13 +25,17 @@ impl GameController {
                 mv = self.game.create_str_move(&input_getter.get_input());
             }
 
-            self.game.make_move(mv.unwrap());
-            if let Some(res) = self.game.result() {
+            let outcome = match self.game.make_move(mv.unwrap()) {
+                Ok(outcome) => outcome,
+                Err(GameOverError(result)) => MoveOutcome::GameOver { result },
+            };
+            if let MoveOutcome::GameOver { result } = outcome {
                 println!("{}", self.game.board());
-                match res {
+                match result {
                     GameResult::WhiteWins => println!("White wins!"),
                     GameResult::BlackWins => println!("Black wins!"),
-                    GameResult::Draw { .. } => println!("It's a draw!")
+                    GameResult::Draw { .. } => println!("It's a draw!"),
+                    GameResult::TimeForfeit { .. } => println!("Out of time!")
                 }
                 break;
             }
@@ -0,0 +1,105 @@
+//! Raw attack sets for each piece type.
+//!
+//! These functions look up the precomputed attack tables used by the move generator. They do not
+//! take a [`ChessBoard`](crate::ChessBoard), so the returned [`BitBoard`]'s include squares
+//! occupied by friendly pieces.
+
+use crate::chessboard::tables::{
+    get_bishop_attacks, get_king_attacks, get_knight_attacks, get_pawn_attacks, get_rook_attacks,
+};
+use crate::defs::*;
+
+/// Gets a [`BitBoard`] of the squares a pawn of a given [`Color`] attacks from a [`Square`].
+///
+/// Only diagonal captures are included, pawn pushes are not.
+///
+/// # Examples
+/// ```
+/// use rchess::{attacks, BitBoard, Color, Square};
+///
+/// assert_eq!(attacks::pawn(Square::E4, Color::White), BitBoard::from_squares(&[Square::D5, Square::F5]));
+/// assert_eq!(attacks::pawn(Square::A7, Color::Black), BitBoard::from_square(Square::B6));
+/// ```
+#[inline]
+pub fn pawn(square: Square, color: Color) -> BitBoard {
+    get_pawn_attacks(square, color)
+}
+
+/// Gets a [`BitBoard`] of the squares a knight attacks from a [`Square`].
+///
+/// # Examples
+/// ```
+/// use rchess::{attacks, BitBoard, Square};
+///
+/// assert_eq!(attacks::knight(Square::A1), BitBoard::from_squares(&[Square::B3, Square::C2]));
+/// assert_eq!(attacks::knight(Square::E4).popcnt(), 8);
+/// ```
+#[inline]
+pub fn knight(square: Square) -> BitBoard {
+    get_knight_attacks(square)
+}
+
+/// Gets a [`BitBoard`] of the squares a king attacks from a [`Square`].
+///
+/// # Examples
+/// ```
+/// use rchess::{attacks, BitBoard, Square};
+///
+/// assert_eq!(attacks::king(Square::A1), BitBoard::from_squares(&[Square::A2, Square::B1, Square::B2]));
+/// assert_eq!(attacks::king(Square::E4).popcnt(), 8);
+/// ```
+#[inline]
+pub fn king(square: Square) -> BitBoard {
+    get_king_attacks(square)
+}
+
+/// Gets a [`BitBoard`] of the squares a bishop attacks from a [`Square`] with a given occupancy.
+///
+/// Blocking squares are included in the attacks.
+///
+/// # Examples
+/// ```
+/// use rchess::{attacks, BitBoard, Square};
+///
+/// let occupancy = BitBoard::from_square(Square::C3);
+/// assert!(attacks::bishop(Square::A1, occupancy).contains(Square::C3));
+/// assert!(!attacks::bishop(Square::A1, occupancy).contains(Square::D4));
+/// ```
+#[inline]
+pub fn bishop(square: Square, occupancy: BitBoard) -> BitBoard {
+    get_bishop_attacks(square, occupancy)
+}
+
+/// Gets a [`BitBoard`] of the squares a rook attacks from a [`Square`] with a given occupancy.
+///
+/// Blocking squares are included in the attacks.
+///
+/// # Examples
+/// ```
+/// use rchess::{attacks, BitBoard, Square};
+///
+/// let occupancy = BitBoard::from_square(Square::A3);
+/// assert!(attacks::rook(Square::A1, occupancy).contains(Square::A3));
+/// assert!(!attacks::rook(Square::A1, occupancy).contains(Square::A4));
+/// assert_eq!(attacks::rook(Square::A1, BitBoard::EMPTY).popcnt(), 14);
+/// ```
+#[inline]
+pub fn rook(square: Square, occupancy: BitBoard) -> BitBoard {
+    get_rook_attacks(square, occupancy)
+}
+
+/// Gets a [`BitBoard`] of the squares a queen attacks from a [`Square`] with a given occupancy.
+///
+/// Blocking squares are included in the attacks.
+///
+/// # Examples
+/// ```
+/// use rchess::{attacks, BitBoard, Square};
+///
+/// let occupancy = BitBoard::EMPTY;
+/// assert_eq!(attacks::queen(Square::E4, occupancy), attacks::bishop(Square::E4, occupancy) | attacks::rook(Square::E4, occupancy));
+/// ```
+#[inline]
+pub fn queen(square: Square, occupancy: BitBoard) -> BitBoard {
+    get_bishop_attacks(square, occupancy) | get_rook_attacks(square, occupancy)
+}
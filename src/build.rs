@@ -5,6 +5,7 @@ use std::path::Path;
 mod defs;
 mod mask_gen;
 mod table_gen;
+mod zobrist_gen;
 
 /// Generates computed tables at compile-time.
 fn main() {
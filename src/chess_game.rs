@@ -1,28 +1,10 @@
 use crate::chessboard::Footprint;
 use crate::{
-    BitBoard, ChessBoard, Color, FenLoadError, Move, MoveCreationError, MoveGen, Piece, PieceType,
-    Square, StrMoveCreationError,
+    ChessBoard, Color, DrawReason, FenLoadError, GameOverError, GameResult, Move,
+    MoveCreationError, MoveGen, MoveOutcome, PieceType, Square, StrMoveCreationError,
 };
 use std::collections::HashMap;
-
-/// The [`GameResult`] enum represents the result of a chess game.
-#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub enum GameResult {
-    WhiteWins,
-    BlackWins,
-    Draw { reason: DrawReason },
-}
-
-/// The [`DrawReason`] enum represents the thing that caused a draw to occur.
-#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub enum DrawReason {
-    InsufficientMaterial,
-    Stalemate,
-    ThreefoldRepetition,
-    FiftyMoves,
-}
+use std::time::Duration;
 
 /// The [`ChessGame`] struct represents a game of chess.
 #[derive(Clone, Debug)]
@@ -31,6 +13,9 @@ pub struct ChessGame {
     /// The game state.
     state: ChessBoard,
 
+    /// The position the game started from.
+    start: ChessBoard,
+
     /// The moves that can be made in the current position.
     position_moves: Vec<Move>,
 
@@ -42,6 +27,9 @@ pub struct ChessGame {
 
     /// The result of the chess game.
     result: Option<GameResult>,
+
+    /// The remaining time of each color, if the game is timed.
+    clock: Option<[Duration; 2]>,
 }
 
 impl ChessGame {
@@ -49,36 +37,79 @@ impl ChessGame {
     #[inline]
     pub fn new() -> Self {
         let state = ChessBoard::new();
-        Self::initialize_game(state)
+        Self::initialize_game(state, &[])
     }
 
     /// Creates a new [`ChessGame`] with the board in the given fen position.
+    ///
+    /// The repetition history starts with the loaded position, so repetitions of positions from
+    /// before it cannot be detected. Use [`ChessGame::with_history`] to include them.
     #[inline]
     pub fn from_fen(fen: &str) -> Result<Self, FenLoadError> {
         let state = ChessBoard::from_fen(fen)?;
-        Ok(Self::initialize_game(state))
+        Ok(Self::initialize_game(state, &[]))
+    }
+
+    /// Creates a new [`ChessGame`] with the board in the given fen position, after the given
+    /// positions of the game.
+    ///
+    /// The prior positions only seed the repetition history, so repetitions that began before
+    /// the loaded position are detected. The game still starts from the loaded position.
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::{ChessBoard, ChessGame, DrawReason};
+    ///
+    /// // The knights went out and back twice, so the start position occurred twice before.
+    /// let moves = ["g1f3", "g8f6", "f3g1", "f6g8", "g1f3", "g8f6", "f3g1", "f6g8"];
+    /// let mut board = ChessBoard::new();
+    /// let mut prior = vec![];
+    /// for mv in moves {
+    ///     prior.push(board.clone());
+    ///     board.apply_uci(mv).unwrap();
+    /// }
+    ///
+    /// let game = ChessGame::with_history(&board.get_fen(), &prior).unwrap();
+    /// assert_eq!(game.claimable_draw(), Some(DrawReason::ThreefoldRepetition));
+    /// ```
+    #[inline]
+    pub fn with_history(fen: &str, prior_positions: &[ChessBoard]) -> Result<Self, FenLoadError> {
+        let state = ChessBoard::from_fen(fen)?;
+        Ok(Self::initialize_game(state, prior_positions))
     }
 
     /// Initializes a new [`ChessGame`].
-    fn initialize_game(state: ChessBoard) -> Self {
+    fn initialize_game(state: ChessBoard, prior_positions: &[ChessBoard]) -> Self {
         // Get the position moves.
         let position_moves = MoveGen::legal(&state).to_vec();
 
         // Initialize repetition history.
         let mut history = HashMap::new();
-        history.insert(state.footprint(), 1);
+        for position in prior_positions.iter().chain([&state]) {
+            let count = history.entry(position.footprint()).or_insert(0u8);
+            *count = count.saturating_add(1);
+        }
+        let repetitions = history[&state.footprint()];
 
         // Create the game object.
         let mut game = Self {
+            start: state.clone(),
             state,
             position_moves,
             history,
             made_moves: vec![],
             result: None,
+            clock: None,
         };
 
-        // Look for terminal state.
-        game.look_for_terminal();
+        // Look for fivefold repetition, then other terminal states.
+        if repetitions >= 5 {
+            game.result = Some(GameResult::Draw {
+                reason: DrawReason::FivefoldRepetition,
+            });
+        } else {
+            game.look_for_terminal();
+        }
         if game.result.is_some() {
             game.position_moves.clear();
         }
@@ -88,23 +119,32 @@ impl ChessGame {
 
     /// Makes a move.
     ///
-    /// If the game is over, an `Err` is returned.
+    /// The returned [`MoveOutcome`] tells if the move ended the game. If the game was already
+    /// over, a [`GameOverError`] is returned.
     ///
     /// # Examples
     /// ```
-    /// use rchess::ChessGame;
+    /// use rchess::{ChessGame, GameOverError, GameResult, MoveOutcome};
     ///
     /// // Create a new chess game
     /// let mut game = ChessGame::new();
     ///
     /// // Make a move in the chess game.
     /// let mv = game.moves()[0];
-    /// game.make_move(mv).unwrap();
+    /// assert_eq!(game.make_move(mv), Ok(MoveOutcome::Ongoing));
+    ///
+    /// // Play fool's mate.
+    /// let mut game = ChessGame::new();
+    /// let mut outcome = MoveOutcome::Ongoing;
+    /// for mv in ["f2f3", "e7e6", "g2g4", "d8h4"] {
+    ///     outcome = game.make_move(game.create_str_move(mv).unwrap()).unwrap();
+    /// }
+    /// assert_eq!(outcome, MoveOutcome::GameOver { result: GameResult::BlackWins });
     /// ```
     #[inline]
-    pub fn make_move(&mut self, mv: Move) -> Result<(), ()> {
-        if self.result.is_some() {
-            return Err(());
+    pub fn make_move(&mut self, mv: Move) -> Result<MoveOutcome, GameOverError> {
+        if let Some(result) = self.result {
+            return Err(GameOverError(result));
         }
 
         self.state.make_move(mv);
@@ -119,12 +159,13 @@ impl ChessGame {
         if let Some(count) = self.history.get_mut(&self.state.footprint()) {
             *count += 1;
 
-            // Look for repetition.
-            if *count == 3 {
+            // Look for fivefold repetition (threefold repetition must be claimed).
+            if *count >= 5 {
                 self.result = Some(GameResult::Draw {
-                    reason: DrawReason::ThreefoldRepetition,
+                    reason: DrawReason::FivefoldRepetition,
                 });
-                return Ok(());
+                self.position_moves.clear();
+                return Ok(self.move_outcome());
             }
         } else {
             self.history.insert(self.state.footprint(), 1);
@@ -133,8 +174,228 @@ impl ChessGame {
         self.position_moves = MoveGen::legal(&self.state).to_vec();
 
         self.look_for_terminal();
+        if self.result.is_some() {
+            self.position_moves.clear();
+        }
+
+        Ok(self.move_outcome())
+    }
+
+    /// Gets the [`MoveOutcome`] of the last move from the result of the game.
+    fn move_outcome(&self) -> MoveOutcome {
+        match self.result {
+            Some(result) => MoveOutcome::GameOver { result },
+            None => MoveOutcome::Ongoing,
+        }
+    }
+
+    /// Gets the [`DrawReason`] of a draw that can currently be claimed, if any.
+    ///
+    /// Threefold repetition and the fifty-move rule do not end the game automatically, they must
+    /// be claimed with [`ChessGame::claim_draw`]. Fivefold repetition and the seventy-five-move
+    /// rule end the game on their own.
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::{ChessGame, DrawReason};
+    ///
+    /// // Create a chess game where fifty moves were made without a capture or pawn move.
+    /// let game = ChessGame::from_fen("7k/8/1r6/8/8/6R1/8/K7 w - - 100").unwrap();
+    /// assert_eq!(game.claimable_draw(), Some(DrawReason::FiftyMoves));
+    ///
+    /// // The game is not over until the draw is claimed.
+    /// assert!(game.result().is_none());
+    /// ```
+    #[inline]
+    pub fn claimable_draw(&self) -> Option<DrawReason> {
+        if self.result.is_some() {
+            return None;
+        }
 
-        Ok(())
+        // Look for threefold repetition.
+        if self
+            .history
+            .get(&self.state.footprint())
+            .is_some_and(|count| *count >= 3)
+        {
+            return Some(DrawReason::ThreefoldRepetition);
+        }
+
+        // Look for 50 move rule.
+        if self.state.can_claim_fifty_move() {
+            return Some(DrawReason::FiftyMoves);
+        }
+
+        None
+    }
+
+    /// Claims a draw if one is available, ending the game.
+    ///
+    /// Returns `true` if the draw was claimed.
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::{ChessGame, DrawReason, GameResult};
+    ///
+    /// // Create a chess game where fifty moves were made without a capture or pawn move.
+    /// let mut game = ChessGame::from_fen("7k/8/1r6/8/8/6R1/8/K7 w - - 100").unwrap();
+    /// assert!(game.claim_draw());
+    /// assert_eq!(game.result(), Some(GameResult::Draw { reason: DrawReason::FiftyMoves }));
+    ///
+    /// // A draw cannot be claimed from the starting position.
+    /// let mut game = ChessGame::new();
+    /// assert!(!game.claim_draw());
+    /// assert!(game.result().is_none());
+    /// ```
+    #[inline]
+    pub fn claim_draw(&mut self) -> bool {
+        match self.claimable_draw() {
+            None => false,
+            Some(reason) => {
+                self.result = Some(GameResult::Draw { reason });
+                self.position_moves.clear();
+                true
+            }
+        }
+    }
+
+    /// Resigns the game on behalf of the given [`Color`], making the opponent the winner.
+    ///
+    /// Nothing happens if the game is already over.
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::{ChessGame, Color, GameResult};
+    ///
+    /// // Create a new chess game.
+    /// let mut game = ChessGame::new();
+    ///
+    /// // White resigns.
+    /// game.resign(Color::White);
+    /// assert_eq!(game.result(), Some(GameResult::BlackWins));
+    ///
+    /// // No more moves can be made.
+    /// assert!(game.moves().is_empty());
+    /// ```
+    #[inline]
+    pub fn resign(&mut self, who: Color) {
+        if self.result.is_some() {
+            return;
+        }
+
+        self.result = Some(match who {
+            Color::White => GameResult::BlackWins,
+            Color::Black => GameResult::WhiteWins,
+        });
+        self.position_moves.clear();
+    }
+
+    /// Ends the game in a draw agreed on by both players.
+    ///
+    /// Nothing happens if the game is already over.
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::{ChessGame, DrawReason, GameResult};
+    ///
+    /// // Create a new chess game.
+    /// let mut game = ChessGame::new();
+    ///
+    /// // The players agree to a draw.
+    /// game.agree_draw();
+    /// assert_eq!(game.result(), Some(GameResult::Draw { reason: DrawReason::Agreement }));
+    /// ```
+    #[inline]
+    pub fn agree_draw(&mut self) {
+        if self.result.is_some() {
+            return;
+        }
+
+        self.result = Some(GameResult::Draw {
+            reason: DrawReason::Agreement,
+        });
+        self.position_moves.clear();
+    }
+
+    /// Starts tracking the remaining time of each color.
+    ///
+    /// Games have no clock unless this is called. Calling it again resets both clocks.
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::{ChessGame, Color};
+    /// use std::time::Duration;
+    ///
+    /// let mut game = ChessGame::new();
+    /// assert_eq!(game.clock(Color::White), None);
+    ///
+    /// game.set_clock(Duration::from_secs(300), Duration::from_secs(180));
+    /// assert_eq!(game.clock(Color::White), Some(Duration::from_secs(300)));
+    /// assert_eq!(game.clock(Color::Black), Some(Duration::from_secs(180)));
+    /// ```
+    #[inline]
+    pub fn set_clock(&mut self, white: Duration, black: Duration) {
+        let mut clock = [Duration::ZERO; 2];
+        clock[Color::White.index()] = white;
+        clock[Color::Black.index()] = black;
+        self.clock = Some(clock);
+    }
+
+    /// Gets the remaining time of the given [`Color`], if the game is timed.
+    #[inline]
+    pub fn clock(&self, color: Color) -> Option<Duration> {
+        self.clock.map(|clock| clock[color.index()])
+    }
+
+    /// Takes the time the side to move spent thinking off its clock.
+    ///
+    /// Call this before making the side's move. If the clock runs out, the game ends with
+    /// [`GameResult::TimeForfeit`], or in a draw by [`DrawReason::InsufficientMaterial`] if the
+    /// opponent cannot checkmate (see [`ChessBoard::has_mating_material`]). Nothing happens if the
+    /// game has no clock or is already over.
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::{ChessGame, Color, GameResult};
+    /// use std::time::Duration;
+    ///
+    /// let mut game = ChessGame::new();
+    /// game.set_clock(Duration::from_secs(10), Duration::from_secs(10));
+    ///
+    /// // White thinks for 4 seconds, then moves.
+    /// game.record_move_time(Duration::from_secs(4));
+    /// game.make_move(game.moves()[0]).unwrap();
+    /// assert_eq!(game.clock(Color::White), Some(Duration::from_secs(6)));
+    ///
+    /// // Black runs out of time.
+    /// game.record_move_time(Duration::from_secs(12));
+    /// assert_eq!(game.clock(Color::Black), Some(Duration::ZERO));
+    /// assert_eq!(game.result(), Some(GameResult::TimeForfeit { loser: Color::Black }));
+    /// assert!(game.moves().is_empty());
+    /// ```
+    #[inline]
+    pub fn record_move_time(&mut self, elapsed: Duration) {
+        if self.result.is_some() {
+            return;
+        }
+
+        let mover = self.state.turn();
+        let Some(clock) = &mut self.clock else {
+            return;
+        };
+
+        let remaining = &mut clock[mover.index()];
+        *remaining = remaining.saturating_sub(elapsed);
+        if remaining.is_zero() {
+            self.result = Some(if self.state.has_mating_material(!mover) {
+                GameResult::TimeForfeit { loser: mover }
+            } else {
+                GameResult::Draw {
+                    reason: DrawReason::InsufficientMaterial,
+                }
+            });
+            self.position_moves.clear();
+        }
     }
 
     /// Looks for a terminal state that is not a repetition.
@@ -153,67 +414,17 @@ impl ChessGame {
             }
         }
 
-        // Look for 50 move rule.
-        if self.state.halfmoves() >= 100 {
+        // Look for 75 move rule (a checkmate on the last move still stands).
+        if self.state.halfmoves() >= 150 && !self.position_moves.is_empty() {
             self.result = Some(GameResult::Draw {
-                reason: DrawReason::FiftyMoves,
+                reason: DrawReason::SeventyFiveMoves,
             })
         }
 
-        if self.state.color_occupancy(Color::White).popcnt() == 1 {
-            if self.state.color_occupancy(Color::Black).popcnt() == 1 {
-                self.result = Some(GameResult::Draw {
-                    reason: DrawReason::InsufficientMaterial,
-                })
-            } else if self.state.color_occupancy(Color::Black).popcnt() == 2 {
-                if !self.state.query(Piece::BLACK_BISHOP).is_empty()
-                    || !self.state.query(Piece::BLACK_KNIGHT).is_empty()
-                {
-                    self.result = Some(GameResult::Draw {
-                        reason: DrawReason::InsufficientMaterial,
-                    })
-                }
-            }
-        } else if self.state.color_occupancy(Color::White).popcnt() == 2 {
-            if self.state.color_occupancy(Color::Black).popcnt() == 2 {
-                if self
-                    .state
-                    .query(Piece::WHITE_BISHOP)
-                    .overlaps(BitBoard::WHITE_SQUARES)
-                {
-                    if self
-                        .state
-                        .query(Piece::BLACK_BISHOP)
-                        .overlaps(BitBoard::WHITE_SQUARES)
-                    {
-                        self.result = Some(GameResult::Draw {
-                            reason: DrawReason::InsufficientMaterial,
-                        })
-                    }
-                } else if self
-                    .state
-                    .query(Piece::WHITE_BISHOP)
-                    .overlaps(BitBoard::BLACK_SQUARES)
-                {
-                    if self
-                        .state
-                        .query(Piece::BLACK_BISHOP)
-                        .overlaps(BitBoard::BLACK_SQUARES)
-                    {
-                        self.result = Some(GameResult::Draw {
-                            reason: DrawReason::InsufficientMaterial,
-                        })
-                    }
-                }
-            } else if self.state.color_occupancy(Color::Black).popcnt() == 1 {
-                if !self.state.query(Piece::WHITE_BISHOP).is_empty()
-                    || !self.state.query(Piece::WHITE_KNIGHT).is_empty()
-                {
-                    self.result = Some(GameResult::Draw {
-                        reason: DrawReason::InsufficientMaterial,
-                    })
-                }
-            }
+        if self.state.is_insufficient_material() {
+            self.result = Some(GameResult::Draw {
+                reason: DrawReason::InsufficientMaterial,
+            })
         }
     }
 
@@ -324,6 +535,44 @@ impl ChessGame {
         &self.made_moves
     }
 
+    /// Gets the position the [`ChessGame`] started from.
+    #[inline]
+    pub fn start_board(&self) -> &ChessBoard {
+        &self.start
+    }
+
+    /// Gets every position reached in the [`ChessGame`].
+    ///
+    /// The first entry is the starting position, followed by the position after each made move.
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::ChessGame;
+    ///
+    /// // Create a new chess game.
+    /// let mut game = ChessGame::new();
+    ///
+    /// // Make the moves "e2e4" and "e7e5".
+    /// game.make_move(game.create_str_move("e2e4").unwrap()).unwrap();
+    /// game.make_move(game.create_str_move("e7e5").unwrap()).unwrap();
+    ///
+    /// let positions = game.positions();
+    /// assert_eq!(positions.len(), 3);
+    /// assert_eq!(positions[0], *game.start_board());
+    /// assert_eq!(positions[2], *game.board());
+    /// ```
+    #[inline]
+    pub fn positions(&self) -> Vec<ChessBoard> {
+        let mut positions = Vec::with_capacity(self.made_moves.len() + 1);
+        let mut board = self.start.clone();
+        for mv in &self.made_moves {
+            positions.push(board.clone());
+            board.make_move(*mv);
+        }
+        positions.push(board);
+        positions
+    }
+
     /// Gets the result of the [`ChessGame`], if any.
     #[inline]
     pub fn result(&self) -> Option<GameResult> {
@@ -335,6 +584,43 @@ impl ChessGame {
     pub fn moves(&self) -> &Vec<Move> {
         &self.position_moves
     }
+
+    /// Gets an iterator over the possible moves for the active color to make.
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::ChessGame;
+    ///
+    /// let game = ChessGame::new();
+    /// assert_eq!(game.legal_moves().count(), 20);
+    /// ```
+    #[inline]
+    pub fn legal_moves(&self) -> impl Iterator<Item = Move> + '_ {
+        self.position_moves.iter().copied()
+    }
+
+    /// Picks a random move for the active color to make.
+    ///
+    /// Returns `None` if the game is over. The random number generator is the re-exported
+    /// [`fastrand`](crate::fastrand).
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::{fastrand, ChessGame};
+    ///
+    /// let mut rng = fastrand::Rng::with_seed(7);
+    ///
+    /// // Play random moves until the game ends.
+    /// let mut game = ChessGame::new();
+    /// while let Some(mv) = game.random_move(&mut rng) {
+    ///     game.make_move(mv).unwrap();
+    /// }
+    /// assert!(game.result().is_some());
+    /// ```
+    #[inline]
+    pub fn random_move(&self, rng: &mut fastrand::Rng) -> Option<Move> {
+        rng.choice(self.position_moves.iter().copied())
+    }
 }
 
 impl Default for ChessGame {
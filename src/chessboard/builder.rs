@@ -37,6 +37,8 @@ pub struct BoardBuilder {
     pub(super) castling_rights: CastlingRights,
     pub(super) en_passant_square: Option<Square>,
     pub(super) hash: ZobristHash,
+    pub(super) pawn_hash: ZobristHash,
+    pub(super) material_hash: ZobristHash,
 }
 
 impl BoardBuilder {
@@ -51,9 +53,41 @@ impl BoardBuilder {
             castling_rights: CastlingRights::new(),
             en_passant_square: None,
             hash: ZobristHash::new(),
+            pawn_hash: ZobristHash::new(),
+            material_hash: ZobristHash::new(),
         }
     }
 
+    /// Creates a new [`BoardBuilder`] with the pieces of an array indexed by [`Square::index`].
+    ///
+    /// Each piece is added as if by [`BoardBuilder::piece`], so the same errors are returned.
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::{BoardBuilder, ChessBoard, Color, Piece, Square};
+    ///
+    /// // Create an array with two kings.
+    /// let mut pieces = [None; 64];
+    /// pieces[Square::A1.index()] = Some(Piece::WHITE_KING);
+    /// pieces[Square::H8.index()] = Some(Piece::BLACK_KING);
+    ///
+    /// // Convert the array into a chess board.
+    /// let board = BoardBuilder::from_array(pieces).unwrap()
+    ///     .turn(Color::White).unwrap()
+    ///     .finish().unwrap();
+    /// assert_eq!(board.to_array(), pieces);
+    /// ```
+    #[inline]
+    pub fn from_array(pieces: [Option<Piece>; 64]) -> Result<Self, BoardBuilderError> {
+        let mut builder = Self::new();
+        for square in SQUARES {
+            if let Some(piece) = pieces[square.index()] {
+                builder = builder.piece(square, piece)?;
+            }
+        }
+        Ok(builder)
+    }
+
     /// Adds a piece to the [`BoardBuilder`].
     ///
     /// # Examples
@@ -78,25 +112,23 @@ impl BoardBuilder {
             {
                 return Err(BoardBuilderError::TwoKings);
             }
-        } else if piece.kind == PieceType::Pawn {
-            match piece.color {
-                Color::White => {
-                    if square.rank() == Rank::Eighth {
-                        return Err(BoardBuilderError::PawnOnLast);
-                    }
-                }
-                Color::Black => {
-                    if square.rank() == Rank::First {
-                        return Err(BoardBuilderError::PawnOnLast);
-                    }
-                }
-            }
+        } else if piece.kind == PieceType::Pawn
+            && square.rank() == Rank::Eighth.relative_to(piece.color)
+        {
+            return Err(BoardBuilderError::PawnOnLast);
         }
 
         if self.piece_map[square.index()].is_some() {
             return Err(BoardBuilderError::TwoPieces);
         }
 
+        let count =
+            (self.piece_bbs[piece.kind.index()] & self.color_bbs[piece.color.index()]).popcnt();
+        self.material_hash.material(piece, count);
+        if piece.kind == PieceType::Pawn {
+            self.pawn_hash.piece(square, piece);
+        }
+
         self.piece_map[square.index()] = Some(piece);
         self.piece_bbs[piece.kind.index()] |= square.bitboard();
         self.color_bbs[piece.color.index()] |= square.bitboard();
@@ -1,14 +1,20 @@
+use super::movegen::generator::generate_king_flight_squares;
 use super::tables;
 use super::zobrist::ZobristHash;
 use crate::chessboard::builder::{BoardBuilder, BoardBuilderError};
 use crate::chessboard::castling_rights::CastlingRights;
+use crate::chessboard::imbalance::Imbalance;
+use crate::chessboard::piece_values::PieceValues;
+use crate::chessboard::polyglot_random::POLYGLOT_RANDOM;
 use crate::chessboard::tables::{
     get_bishop_attacks, get_king_attacks, get_knight_attacks, get_pawn_attacks, get_rook_attacks,
 };
+use crate::chessboard::transform::Transform;
 use crate::defs::*;
-use crate::{MoveGen, StrMoveCreationError};
-use std::fmt::{Debug, Display, Formatter};
-use std::hash::{Hash, Hasher};
+use crate::{DrawReason, GameResult, MoveGen, StrMoveCreationError, TerminalState};
+use core::cmp::Ordering;
+use core::fmt::{Debug, Display, Formatter};
+use core::hash::{Hash, Hasher};
 use thiserror::Error;
 
 /// The [`Move`] enum represents a move on a chess board.
@@ -50,6 +56,20 @@ pub enum Move {
     },
 }
 
+/// The [`CheckType`] enum represents how the side to move is in check.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CheckType {
+    /// The king is not in check.
+    None,
+
+    /// The king is checked by the piece on the given square.
+    Single(Square),
+
+    /// The king is checked by two pieces, so only king moves are legal.
+    Double,
+}
+
 /// The [`BuilderConversionError`] enum is the error type for converting a [`BoardBuilder`] to a [`ChessBoard`].
 #[derive(Error, Debug, Copy, Clone, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -73,6 +93,49 @@ pub enum BuilderConversionError {
     TooManyPieces,
 }
 
+/// The [`PositionError`] enum is the error type for [`ChessBoard::validate`].
+#[derive(Error, Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PositionError {
+    #[error("a pawn was on the first or eighth rank")]
+    PawnOnBackRank,
+
+    #[error("more than 8 pawns were set for a given color")]
+    TooManyPawns,
+
+    #[error("a color has more promoted pieces than it has missing pawns")]
+    TooManyPromotedPieces,
+
+    #[error("the en passant square was invalid")]
+    InvalidEnPassant,
+}
+
+/// The [`IllegalMoveError`] struct is the error type for [`ChessBoard::try_make_move`].
+///
+/// It holds the [`Move`] that was rejected.
+#[derive(Error, Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[error("the move {0} was illegal")]
+pub struct IllegalMoveError(pub Move);
+
+/// The [`StrMovesError`] struct is the error type for [`ChessBoard::from_str_moves`].
+///
+/// It holds the index and text of the move that could not be made, and why.
+#[derive(Error, Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[error("move {} '{input}' could not be made: {error}", .index + 1)]
+pub struct StrMovesError<'a> {
+    /// The index of the move in the list of moves.
+    pub index: usize,
+
+    /// The text of the move.
+    pub input: &'a str,
+
+    /// The reason the move could not be made.
+    #[source]
+    pub error: StrMoveCreationError,
+}
+
 /// The [`FenLoadError`] enum is the error type for loading a fen position.
 #[derive(Error, Debug, Copy, Clone, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -120,6 +183,27 @@ pub enum FenFormatError {
 }
 
 /// The [`Footprint`] struct is used to identify a [`ChessBoard`] without extra computed data.
+///
+/// Two [`Footprint`]s are equal when their boards have the same pieces, turn, castling rights and
+/// en passant square, so the half move clock is ignored. Hashing a [`Footprint`] only writes its
+/// [`ZobristHash`], which makes it a cheap and collision-safe key for a `HashMap`, such as a
+/// transposition table or the repetition history of a [`ChessGame`](crate::ChessGame).
+///
+/// # Examples
+/// ```
+/// use rchess::{ChessBoard, Footprint, MoveGen};
+/// use std::collections::HashMap;
+///
+/// // A transposition table storing the number of legal moves of each position.
+/// let mut table: HashMap<Footprint, usize> = HashMap::new();
+///
+/// let board = ChessBoard::from_str_moves(&["e2e4", "g8f6", "g1f3", "b8c6"]).unwrap();
+/// table.insert(board.footprint(), MoveGen::legal(&board).len());
+///
+/// // The same position reached by another move order is found in the table.
+/// let transposed = ChessBoard::from_str_moves(&["g1f3", "b8c6", "e2e4", "g8f6"]).unwrap();
+/// assert_eq!(table.get(&transposed.footprint()), Some(&MoveGen::legal(&board).len()));
+/// ```
 #[derive(Clone, Eq, PartialEq, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Footprint {
@@ -131,6 +215,22 @@ pub struct Footprint {
     hash: ZobristHash,
 }
 
+impl Footprint {
+    /// Gets the hash of the [`ChessBoard`] the [`Footprint`] was made from.
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::ChessBoard;
+    ///
+    /// let board = ChessBoard::new();
+    /// assert_eq!(board.footprint().hash(), board.hash());
+    /// ```
+    #[inline]
+    pub fn hash(&self) -> ZobristHash {
+        self.hash
+    }
+}
+
 impl Hash for Footprint {
     fn hash<H: Hasher>(&self, state: &mut H) {
         state.write_u64(self.hash.to_u64());
@@ -165,6 +265,12 @@ pub struct ChessBoard {
     /// A hash of the board state.
     hash: ZobristHash,
 
+    /// A hash of the pawn placement.
+    pawn_hash: ZobristHash,
+
+    /// A hash of the piece counts.
+    material_hash: ZobristHash,
+
     /// The half move clock.
     half_move_clock: u8,
 }
@@ -186,12 +292,21 @@ impl ChessBoard {
     ///
     /// // A chess board with three moves made from the starting position.
     /// let board = ChessBoard::from_str_moves(&["e2e4", "e7e6", "g1f3"]).unwrap();
+    ///
+    /// // The error tells which move could not be made.
+    /// let err = ChessBoard::from_str_moves(&["e2e4", "e7e6", "e4e6"]).unwrap_err();
+    /// assert_eq!((err.index, err.input), (2, "e4e6"));
+    /// assert_eq!(err.to_string(), "move 3 'e4e6' could not be made: the move was illegal");
     /// ```
     #[inline]
-    pub fn from_str_moves(moves: &[&str]) -> Result<Self, StrMoveCreationError> {
+    pub fn from_str_moves<'a>(moves: &[&'a str]) -> Result<Self, StrMovesError<'a>> {
         let mut board = Self::new();
-        for str_move in moves {
-            let mv = MoveGen::create_str_move(&board, *str_move)?;
+        for (index, str_move) in moves.iter().enumerate() {
+            let mv = MoveGen::create_str_move(&board, str_move).map_err(|error| StrMovesError {
+                index,
+                input: str_move,
+                error,
+            })?;
             board.make_move(mv);
         }
         Ok(board)
@@ -200,6 +315,36 @@ impl ChessBoard {
     /// Attempts to create a new [`ChessBoard`] from the given fen string.
     #[inline]
     pub fn from_fen(fen: &str) -> Result<Self, FenLoadError> {
+        Self::load_fen(fen, false)
+    }
+
+    /// Attempts to create a new [`ChessBoard`] from the given fen string, dropping impossible
+    /// castling rights.
+    ///
+    /// Unlike [`ChessBoard::from_fen`], castling rights without the king and rook on their
+    /// starting squares, and castling rights that are given twice, are ignored instead of
+    /// rejected. This is useful for loading fens from sources that always write `KQkq`.
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::{CastleSide, ChessBoard, Color};
+    ///
+    /// // The black rooks have moved, so black cannot castle.
+    /// let fen = "1r2k1r1/8/8/8/8/8/8/R3K2R w KQkq -";
+    /// assert!(ChessBoard::from_fen(fen).is_err());
+    ///
+    /// let board = ChessBoard::from_fen_lenient(fen).unwrap();
+    /// assert!(board.is_castle_right_set(CastleSide::Kingside, Color::White));
+    /// assert!(!board.is_castle_right_set(CastleSide::Kingside, Color::Black));
+    /// assert_eq!(board, ChessBoard::from_fen("1r2k1r1/8/8/8/8/8/8/R3K2R w KQ -").unwrap());
+    /// ```
+    #[inline]
+    pub fn from_fen_lenient(fen: &str) -> Result<Self, FenLoadError> {
+        Self::load_fen(fen, true)
+    }
+
+    /// Loads a fen string, dropping impossible castling rights if `lenient` is set.
+    fn load_fen(fen: &str, lenient: bool) -> Result<Self, FenLoadError> {
         // Create a board builder.
         let mut builder = BoardBuilder::new();
 
@@ -207,28 +352,8 @@ impl ChessBoard {
         let mut fen = fen.split_whitespace();
 
         // Load fen piece positions.
-        let mut square_idx = Square::A8.as_u8();
         let fen_pieces = fen.next().ok_or(FenFormatError::MissingPieceSection)?;
-        for c in fen_pieces.chars() {
-            match c {
-                // Insert a piece.
-                'p' | 'n' | 'b' | 'r' | 'q' | 'k' | 'P' | 'N' | 'B' | 'R' | 'Q' | 'K' => {
-                    let square =
-                        Square::from_u8(square_idx).ok_or(FenFormatError::InvalidPieceSection)?;
-                    builder = builder.piece(square, Piece::from_char(c).unwrap())?;
-                    square_idx += 1;
-                }
-                // Skip empty squares.
-                '1'..='8' => {
-                    let empty_squares = c.to_digit(10).unwrap() as u8;
-                    square_idx += empty_squares;
-                }
-                // Move to the next line.
-                '/' => square_idx -= 16,
-                // Unrecognized character.
-                _ => return Err(FenFormatError::InvalidPieceSection.into()),
-            }
-        }
+        builder = Self::load_fen_pieces(builder, fen_pieces)?;
 
         // Load fen turn.
         let fen_turn = fen.next().ok_or(FenFormatError::MissingTurnSection)?;
@@ -243,13 +368,21 @@ impl ChessBoard {
         if fen_castling_rights == "-" {
         } else {
             for c in fen_castling_rights.chars() {
-                match c {
-                    'K' => builder = builder.castle_right(CastleSide::Kingside, Color::White)?,
-                    'Q' => builder = builder.castle_right(CastleSide::Queenside, Color::White)?,
-                    'k' => builder = builder.castle_right(CastleSide::Kingside, Color::Black)?,
-                    'q' => builder = builder.castle_right(CastleSide::Queenside, Color::Black)?,
+                let (side, color) = match c {
+                    'K' => (CastleSide::Kingside, Color::White),
+                    'Q' => (CastleSide::Queenside, Color::White),
+                    'k' => (CastleSide::Kingside, Color::Black),
+                    'q' => (CastleSide::Queenside, Color::Black),
                     _ => return Err(FenFormatError::InvalidCastleRights.into()),
+                };
+
+                if lenient
+                    && (builder.castling_rights.is_set(side, color)
+                        || !Self::castle_right_is_possible(&builder.piece_map, side, color))
+                {
+                    continue;
                 }
+                builder = builder.castle_right(side, color)?;
             }
         }
 
@@ -263,21 +396,97 @@ impl ChessBoard {
 
         let mut board = Self::from_builder(builder)?;
 
-        // Load halfmove clock (if provided).
+        // Load halfmove clock (if provided). Clocks past the fifty and seventy-five-move limits
+        // are kept so drawn positions can be loaded, and saturate like they do when moves are made.
         if let Some(halfmoves) = fen.next() {
-            match halfmoves.parse::<u8>() {
-                Ok(halfmoves) if halfmoves <= 100 => board.half_move_clock = halfmoves,
-                _ => {
-                    return Err(FenLoadError::Formatting(
-                        FenFormatError::InvalidHalfMoveSection,
-                    ))
-                }
+            if halfmoves.is_empty() || !halfmoves.bytes().all(|byte| byte.is_ascii_digit()) {
+                return Err(FenLoadError::Formatting(
+                    FenFormatError::InvalidHalfMoveSection,
+                ));
             }
+
+            // A string of digits only fails to parse when it is too large.
+            board.half_move_clock = halfmoves.parse().unwrap_or(u8::MAX);
         }
 
         Ok(board)
     }
 
+    /// Attempts to create a new [`ChessBoard`] from the piece placement section of a fen string.
+    ///
+    /// No castling rights or en passant square are set, and the half move clock starts at zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::{ChessBoard, Color};
+    ///
+    /// // A king and queen against a king.
+    /// let board = ChessBoard::from_placement("4k3/8/8/8/8/8/8/3QK3", Color::White).unwrap();
+    /// assert_eq!(board, ChessBoard::from_fen("4k3/8/8/8/8/8/8/3QK3 w - - 0").unwrap());
+    /// ```
+    #[inline]
+    pub fn from_placement(fen_pieces: &str, turn: Color) -> Result<Self, FenLoadError> {
+        let builder = Self::load_fen_pieces(BoardBuilder::new(), fen_pieces)?.turn(turn)?;
+        Ok(Self::from_builder(builder)?)
+    }
+
+    /// Returns `true` if the king and rook needed for a castling right are on their starting
+    /// squares.
+    fn castle_right_is_possible(
+        piece_map: &[Option<Piece>; 64],
+        side: CastleSide,
+        color: Color,
+    ) -> bool {
+        let (king, rook) = match (color, side) {
+            (Color::White, CastleSide::Kingside) => (Square::E1, Square::H1),
+            (Color::White, CastleSide::Queenside) => (Square::E1, Square::A1),
+            (Color::Black, CastleSide::Kingside) => (Square::E8, Square::H8),
+            (Color::Black, CastleSide::Queenside) => (Square::E8, Square::A8),
+        };
+        piece_map[king.index()] == Some(Piece::new(PieceType::King, color))
+            && piece_map[rook.index()] == Some(Piece::new(PieceType::Rook, color))
+    }
+
+    /// Gets the start and end squares of the rook when the given [`Color`] castles on a side.
+    #[inline]
+    fn castle_rook_squares(color: Color, side: CastleSide) -> (Square, Square) {
+        match (color, side) {
+            (Color::White, CastleSide::Kingside) => (Square::H1, Square::F1),
+            (Color::White, CastleSide::Queenside) => (Square::A1, Square::D1),
+            (Color::Black, CastleSide::Kingside) => (Square::H8, Square::F8),
+            (Color::Black, CastleSide::Queenside) => (Square::A8, Square::D8),
+        }
+    }
+
+    /// Adds the pieces from the piece placement section of a fen string to a [`BoardBuilder`].
+    fn load_fen_pieces(
+        mut builder: BoardBuilder,
+        fen_pieces: &str,
+    ) -> Result<BoardBuilder, FenLoadError> {
+        let mut square_idx = Square::A8.as_u8();
+        for c in fen_pieces.chars() {
+            match c {
+                // Insert a piece.
+                'p' | 'n' | 'b' | 'r' | 'q' | 'k' | 'P' | 'N' | 'B' | 'R' | 'Q' | 'K' => {
+                    let square =
+                        Square::from_u8(square_idx).ok_or(FenFormatError::InvalidPieceSection)?;
+                    builder = builder.piece(square, Piece::from_char(c).unwrap())?;
+                    square_idx += 1;
+                }
+                // Skip empty squares.
+                '1'..='8' => {
+                    let empty_squares = c.to_digit(10).unwrap() as u8;
+                    square_idx += empty_squares;
+                }
+                // Move to the next line.
+                '/' => square_idx -= 16,
+                // Unrecognized character.
+                _ => return Err(FenFormatError::InvalidPieceSection.into()),
+            }
+        }
+        Ok(builder)
+    }
+
     /// Creates a new [`ChessBoard`] from the given [`BoardBuilder`].
     ///
     /// # Examples
@@ -339,47 +548,13 @@ impl ChessBoard {
             }
         }
 
-        if board_builder
-            .castling_rights
-            .is_set(CastleSide::Kingside, Color::White)
-        {
-            if board_builder.piece_map[Square::E1.index()] != Some(Piece::WHITE_KING)
-                || board_builder.piece_map[Square::H1.index()] != Some(Piece::WHITE_ROOK)
-            {
-                return Err(BuilderConversionError::InvalidCastleRight);
-            }
-        }
-
-        if board_builder
-            .castling_rights
-            .is_set(CastleSide::Queenside, Color::White)
-        {
-            if board_builder.piece_map[Square::E1.index()] != Some(Piece::WHITE_KING)
-                || board_builder.piece_map[Square::A1.index()] != Some(Piece::WHITE_ROOK)
-            {
-                return Err(BuilderConversionError::InvalidCastleRight);
-            }
-        }
-
-        if board_builder
-            .castling_rights
-            .is_set(CastleSide::Kingside, Color::Black)
-        {
-            if board_builder.piece_map[Square::E8.index()] != Some(Piece::BLACK_KING)
-                || board_builder.piece_map[Square::H8.index()] != Some(Piece::BLACK_ROOK)
-            {
-                return Err(BuilderConversionError::InvalidCastleRight);
-            }
-        }
-
-        if board_builder
-            .castling_rights
-            .is_set(CastleSide::Kingside, Color::Black)
-        {
-            if board_builder.piece_map[Square::E8.index()] != Some(Piece::BLACK_KING)
-                || board_builder.piece_map[Square::A8.index()] != Some(Piece::BLACK_ROOK)
-            {
-                return Err(BuilderConversionError::InvalidCastleRight);
+        for color in [Color::White, Color::Black] {
+            for side in [CastleSide::Kingside, CastleSide::Queenside] {
+                if board_builder.castling_rights.is_set(side, color)
+                    && !Self::castle_right_is_possible(&board_builder.piece_map, side, color)
+                {
+                    return Err(BuilderConversionError::InvalidCastleRight);
+                }
             }
         }
 
@@ -387,14 +562,24 @@ impl ChessBoard {
             piece_bbs: board_builder.piece_bbs,
             color_bbs: board_builder.color_bbs,
             castling_rights: board_builder.castling_rights,
-            en_passant: None,
+            en_passant: board_builder.en_passant_square,
             turn,
             pinned: BitBoard::EMPTY,
             checkers: BitBoard::EMPTY,
             hash: board_builder.hash,
+            pawn_hash: board_builder.pawn_hash,
+            material_hash: board_builder.material_hash,
             half_move_clock: 0,
         };
 
+        // The builder always hashes the en passant square, but it only belongs in the hash when
+        // it can be captured.
+        if let Some(square) = chessboard.en_passant {
+            if !chessboard.ep_is_capturable() {
+                chessboard.hash.ep(square);
+            }
+        }
+
         if chessboard.is_attacked(
             chessboard.get_king_square(!chessboard.turn),
             chessboard.turn,
@@ -407,6 +592,73 @@ impl ChessBoard {
         Ok(chessboard)
     }
 
+    /// Checks that the [`ChessBoard`] could have been reached in a real game.
+    ///
+    /// This goes beyond the checks done when a [`ChessBoard`] is created, and looks for pawns on
+    /// the first or eighth rank, more than 8 pawns for a color, more promoted pieces than a color
+    /// has missing pawns, and an en passant square that does not follow a double pawn push.
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::{ChessBoard, PositionError};
+    ///
+    /// // The starting position is valid.
+    /// assert_eq!(ChessBoard::new().validate(), Ok(()));
+    ///
+    /// // White cannot have three queens without losing two pawns.
+    /// let board = ChessBoard::from_fen("4k3/8/8/8/8/8/PPPPPPP1/QQQ1K3 w - -").unwrap();
+    /// assert_eq!(board.validate(), Err(PositionError::TooManyPromotedPieces));
+    /// ```
+    #[inline]
+    pub fn validate(&self) -> Result<(), PositionError> {
+        let back_ranks = BitBoard::from_rank(Rank::First) | BitBoard::from_rank(Rank::Eighth);
+        if self.piece_bbs[PieceType::Pawn.index()].overlaps(back_ranks) {
+            return Err(PositionError::PawnOnBackRank);
+        }
+
+        for color in [Color::White, Color::Black] {
+            let count = |kind: PieceType| self.query((kind, color)).popcnt();
+
+            let pawns = count(PieceType::Pawn);
+            if pawns > 8 {
+                return Err(PositionError::TooManyPawns);
+            }
+
+            // Pieces beyond the starting set must have been promoted from pawns.
+            let bishops = self.query((PieceType::Bishop, color));
+            let promoted = count(PieceType::Knight).saturating_sub(2)
+                + (bishops & BitBoard::WHITE_SQUARES)
+                    .popcnt()
+                    .saturating_sub(1)
+                + (bishops & BitBoard::BLACK_SQUARES)
+                    .popcnt()
+                    .saturating_sub(1)
+                + count(PieceType::Rook).saturating_sub(2)
+                + count(PieceType::Queen).saturating_sub(1);
+            if promoted > 8 - pawns {
+                return Err(PositionError::TooManyPromotedPieces);
+            }
+        }
+
+        if let Some(sq) = self.en_passant {
+            // The pawn that double pushed, and the squares it passed over.
+            let (pushed, passed) = match self.turn {
+                Color::White => (sq.down(), sq.bitboard() | sq.bitboard().up()),
+                Color::Black => (sq.up(), sq.bitboard() | sq.bitboard().down()),
+            };
+
+            if sq.rank() != Rank::Sixth.relative_to(self.turn)
+                || pushed.and_then(|sq| self.piece_at(sq))
+                    != Some((PieceType::Pawn, !self.turn).into())
+                || passed.overlaps(self.occupancy())
+            {
+                return Err(PositionError::InvalidEnPassant);
+            }
+        }
+
+        Ok(())
+    }
+
     /// Copies the [`ChessBoard`] and makes a move on it.
     ///
     /// # Examples
@@ -433,6 +685,77 @@ impl ChessBoard {
         child
     }
 
+    /// Gets the [`ChessBoard`] with a given [`Color`] to move.
+    ///
+    /// Unlike a null move, the half move clock is not changed. The en passant square is cleared
+    /// when the turn changes, since it belongs to the previous side to move. If the side that
+    /// would no longer be moving is in check, an `Err` is returned.
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::{ChessBoard, Color};
+    ///
+    /// let board = ChessBoard::new().with_turn(Color::Black).unwrap();
+    /// assert_eq!(
+    ///     board,
+    ///     ChessBoard::from_fen("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR b KQkq -").unwrap()
+    /// );
+    ///
+    /// // White is in check, so it cannot be black's turn.
+    /// let board = ChessBoard::from_fen("4k3/8/8/8/8/8/8/r3K3 w - -").unwrap();
+    /// assert!(board.with_turn(Color::Black).is_err());
+    /// ```
+    #[inline]
+    pub fn with_turn(mut self, color: Color) -> Result<ChessBoard, BuilderConversionError> {
+        if self.turn == color {
+            return Ok(self);
+        }
+
+        self.clear_ep();
+        self.toggle_turn();
+
+        if self.is_attacked(self.get_king_square(!self.turn), self.turn) {
+            return Err(BuilderConversionError::InactiveKingAttacked);
+        }
+
+        self.calculate_extra_data();
+
+        Ok(self)
+    }
+
+    /// Copies the [`ChessBoard`] and passes the turn on it, which is also called a null move.
+    ///
+    /// The en passant square is cleared and the half move clock goes up by one. Returns `None` if
+    /// the side to move is in check, since passing would leave its king attacked.
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::{ChessBoard, MoveGen};
+    ///
+    /// // If it were black's turn, black could capture the queen.
+    /// let board = ChessBoard::from_fen("4k3/8/8/8/8/8/3r4/3QK3 w - -").unwrap();
+    /// let passed = board.null_move_board().unwrap();
+    /// assert!(MoveGen::create_str_move(&passed, "d2d1").unwrap().is_capture());
+    ///
+    /// // White cannot pass while in check.
+    /// let board = ChessBoard::from_fen("4k3/8/8/8/8/8/8/r3K3 w - -").unwrap();
+    /// assert_eq!(board.null_move_board(), None);
+    /// ```
+    #[inline]
+    pub fn null_move_board(&self) -> Option<ChessBoard> {
+        if !self.checkers.is_empty() {
+            return None;
+        }
+
+        let mut board = self.clone();
+        board.clear_ep();
+        board.toggle_turn();
+        board.half_move_clock = board.half_move_clock.saturating_add(1);
+        board.calculate_extra_data();
+
+        Some(board)
+    }
+
     /// Gets a fen string representing the [`ChessBoard`].
     ///
     /// # Examples
@@ -443,6 +766,7 @@ impl ChessBoard {
     /// let board = ChessBoard::new();
     /// assert_eq!(&board.get_fen(), "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq -");
     /// ```
+    #[cfg(feature = "std")]
     #[inline]
     pub fn get_fen(&self) -> String {
         // Create the fen piece section.
@@ -517,12 +841,114 @@ impl ChessBoard {
         )
     }
 
+    /// Makes a move on the [`ChessBoard`] if it is legal.
+    ///
+    /// Unlike [`ChessBoard::make_move`], the move is checked against the legal moves first, so it
+    /// is safe to use with moves from untrusted sources. The [`ChessBoard`] is left unchanged if
+    /// the move is illegal.
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::{ChessBoard, IllegalMoveError, Move, PieceType, Square};
+    ///
+    /// let mut board = ChessBoard::new();
+    ///
+    /// let mv = Move::DoublePawnPush { start: Square::E2, end: Square::E4 };
+    /// assert_eq!(board.try_make_move(mv), Ok(()));
+    ///
+    /// let mv = Move::Quiet { start: Square::E4, end: Square::E6, moving: PieceType::Pawn };
+    /// assert_eq!(board.try_make_move(mv), Err(IllegalMoveError(mv)));
+    /// ```
+    #[inline]
+    pub fn try_make_move(&mut self, mv: Move) -> Result<(), IllegalMoveError> {
+        if !MoveGen::legal(self).any(|legal| legal == mv) {
+            return Err(IllegalMoveError(mv));
+        }
+        self.make_move(mv);
+        Ok(())
+    }
+
+    /// Makes a move given in UCI notation on the [`ChessBoard`], and returns the [`Move`].
+    ///
+    /// The [`ChessBoard`] is left unchanged if the move cannot be created.
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::{ChessBoard, Move, Square};
+    ///
+    /// let mut board = ChessBoard::new();
+    ///
+    /// let mv = board.apply_uci("e2e4").unwrap();
+    /// assert_eq!(mv, Move::DoublePawnPush { start: Square::E2, end: Square::E4 });
+    /// assert!(board.apply_uci("e2e4").is_err());
+    /// ```
+    #[inline]
+    pub fn apply_uci(&mut self, uci: &str) -> Result<Move, StrMoveCreationError> {
+        let mv = MoveGen::create_str_move(self, uci)?;
+        self.make_move(mv);
+        Ok(mv)
+    }
+
+    /// Makes a move given in standard algebraic notation (SAN) on the [`ChessBoard`], and
+    /// returns the [`Move`].
+    ///
+    /// The [`ChessBoard`] is left unchanged if the move cannot be created. See
+    /// [`MoveGen::create_san_move`] for the accepted notation.
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::{ChessBoard, Move, PieceType, Square};
+    ///
+    /// let mut board = ChessBoard::new();
+    ///
+    /// board.apply_san("e4").unwrap();
+    /// board.apply_san("e5").unwrap();
+    /// let mv = board.apply_san("Nf3").unwrap();
+    /// assert_eq!(mv, Move::Quiet { start: Square::G1, end: Square::F3, moving: PieceType::Knight });
+    /// ```
+    #[inline]
+    pub fn apply_san(&mut self, san: &str) -> Result<Move, StrMoveCreationError> {
+        let mv = MoveGen::create_san_move(self, san)?;
+        self.make_move(mv);
+        Ok(mv)
+    }
+
     /// Makes a move on the [`ChessBoard`].
     ///
     /// # Warning
     /// If the move was not generated by a [`MoveGen`], behavior is undefined.
+    ///
+    /// In debug builds, moves that do not match the pieces on the board panic instead of
+    /// corrupting the [`ChessBoard`].
     #[inline]
     pub fn make_move(&mut self, mv: Move) {
+        self.make_move_capturing(mv);
+    }
+
+    /// Makes a move on the [`ChessBoard`] and returns the captured [`Piece`], if any.
+    ///
+    /// The pawn removed by an en passant capture is returned as well.
+    ///
+    /// # Warning
+    /// If the move was not generated by a [`MoveGen`], behavior is undefined.
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::{ChessBoard, Color, Move, Piece, PieceType, Square};
+    ///
+    /// let mut board = ChessBoard::from_fen("4k3/8/8/3pP3/8/8/8/4K3 w - d6").unwrap();
+    ///
+    /// let mv = Move::EnPassant { start: Square::E5, end: Square::D6 };
+    /// assert_eq!(
+    ///     board.make_move_capturing(mv),
+    ///     Some(Piece::new(PieceType::Pawn, Color::Black))
+    /// );
+    ///
+    /// let mv = Move::Quiet { start: Square::E8, end: Square::D8, moving: PieceType::King };
+    /// assert_eq!(board.make_move_capturing(mv), None);
+    /// ```
+    #[inline]
+    pub fn make_move_capturing(&mut self, mv: Move) -> Option<Piece> {
         // Get data about the current move & board state.
         let us = self.turn;
         let them = !self.turn;
@@ -531,6 +957,7 @@ impl ChessBoard {
         self.toggle_turn();
 
         let mut reset_halfmoves = false;
+        let mut captured_piece = None;
         match mv {
             Move::Quiet { start, end, moving } => {
                 // Remove relevant castling rights for moving kings or rooks.
@@ -598,7 +1025,12 @@ impl ChessBoard {
                 }
 
                 // Remove the captured piece.
-                self.remove(end);
+                debug_assert!(
+                    self.color_bbs[them.index()].contains(end),
+                    "expected an enemy piece on square {}",
+                    end
+                );
+                captured_piece = Some(self.remove(end));
 
                 // Move the piece.
                 self.move_piece(start, end, (moving, us));
@@ -607,12 +1039,7 @@ impl ChessBoard {
             }
             Move::Castle { start, end, side } => {
                 // Get rook start & end squares.
-                let (rook_start, rook_end) = match (us, side) {
-                    (Color::Black, CastleSide::Queenside) => (Square::A8, Square::D8),
-                    (Color::Black, CastleSide::Kingside) => (Square::H8, Square::F8),
-                    (Color::White, CastleSide::Queenside) => (Square::A1, Square::D1),
-                    (Color::White, CastleSide::Kingside) => (Square::H1, Square::F1),
-                };
+                let (rook_start, rook_end) = Self::castle_rook_squares(us, side);
 
                 // Move the rook.
                 self.move_piece(rook_start, rook_end, (PieceType::Rook, us));
@@ -637,10 +1064,16 @@ impl ChessBoard {
             }
             Move::EnPassant { start, end } => {
                 // Capture the en-passanted piece.
-                match us {
-                    Color::White => self.remove(end.down().unwrap()),
-                    Color::Black => self.remove(end.up().unwrap()),
+                let captured = match us {
+                    Color::White => end.down().unwrap(),
+                    Color::Black => end.up().unwrap(),
                 };
+                debug_assert!(
+                    self.piece_at(captured) == Some(Piece::new(PieceType::Pawn, them)),
+                    "expected an enemy pawn on square {}",
+                    captured
+                );
+                captured_piece = Some(self.remove(captured));
 
                 // Move the piece.
                 self.move_piece(start, end, (PieceType::Pawn, us));
@@ -649,6 +1082,11 @@ impl ChessBoard {
             }
             Move::Promote { start, end, target } => {
                 // Remove current piece.
+                debug_assert!(
+                    self.piece_at(start) == Some(Piece::new(PieceType::Pawn, us)),
+                    "expected a pawn on square {}",
+                    start
+                );
                 self.remove(start);
 
                 // Insert the promoted piece.
@@ -675,9 +1113,19 @@ impl ChessBoard {
                 }
 
                 // Remove the captured piece.
-                self.remove(end);
+                debug_assert!(
+                    self.color_bbs[them.index()].contains(end),
+                    "expected an enemy piece on square {}",
+                    end
+                );
+                captured_piece = Some(self.remove(end));
 
                 // Remove current piece.
+                debug_assert!(
+                    self.piece_at(start) == Some(Piece::new(PieceType::Pawn, us)),
+                    "expected a pawn on square {}",
+                    start
+                );
                 self.remove(start);
 
                 // Insert the promoted piece.
@@ -690,17 +1138,26 @@ impl ChessBoard {
         if reset_halfmoves {
             self.half_move_clock = 0;
         } else {
-            self.half_move_clock += 1;
+            // Saturate so long artificial games cannot overflow the clock.
+            self.half_move_clock = self.half_move_clock.saturating_add(1);
         }
 
         // Calculate non-position data.
         self.calculate_extra_data();
+
+        captured_piece
     }
 
     /// Calculates non-positional data for the [`ChessBoard`].
     fn calculate_extra_data(&mut self) {
         self.calculate_pinned();
         self.calculate_checkers();
+
+        debug_assert_eq!(
+            self.hash,
+            self.recompute_hash(),
+            "the incremental hash does not match the recomputed hash"
+        );
     }
 
     /// Calculates the pinned pieces on the [`ChessBoard`].
@@ -735,33 +1192,81 @@ impl ChessBoard {
 
     /// Calculates the checkers for the [`ChessBoard`].
     fn calculate_checkers(&mut self) {
-        // Get extra data about the board.
-        let us = self.turn;
-        let them = !self.turn;
-        let king_sq = self.get_king_square(us);
-
-        // Reset the current checkers bitboard.
-        self.checkers = BitBoard::EMPTY;
-
-        // Look for pawn checkers.
-        let pawn_check_locations = get_pawn_attacks(king_sq, us);
-        self.checkers |= self.query((PieceType::Pawn, them)) & pawn_check_locations;
-
-        // Look for knight checkers.
-        let knight_check_locations = get_knight_attacks(king_sq);
-        self.checkers |= self.query((PieceType::Knight, them)) & knight_check_locations;
+        self.checkers = self.checkers_against(self.turn);
+    }
 
-        // Look for bishop & queen checkers.
-        let bishop_check_locations = get_bishop_attacks(king_sq, self.occupancy());
-        self.checkers |= (self.query((PieceType::Bishop, them))
-            | self.query((PieceType::Queen, them)))
-            & bishop_check_locations;
+    /// Gets a [`BitBoard`] of the pieces of the given [`Color`] that attack a [`Square`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::{BitBoard, ChessBoard, Color, Square};
+    ///
+    /// // Create a new chess board.
+    /// let board = ChessBoard::new();
+    ///
+    /// // The pawns on e2 and g2 and the knight on g1 defend f3.
+    /// assert_eq!(
+    ///     board.attackers_to(Square::F3, Color::White),
+    ///     BitBoard::from_squares(&[Square::E2, Square::G2, Square::G1])
+    /// );
+    /// assert!(board.attackers_to(Square::F3, Color::Black).is_empty());
+    /// ```
+    #[inline]
+    pub fn attackers_to(&self, square: Square, by: Color) -> BitBoard {
+        let bishops = self.query((PieceType::Bishop, by)) | self.query((PieceType::Queen, by));
+        let rooks = self.query((PieceType::Rook, by)) | self.query((PieceType::Queen, by));
+
+        (self.query((PieceType::Pawn, by)) & get_pawn_attacks(square, !by))
+            | (self.query((PieceType::Knight, by)) & get_knight_attacks(square))
+            | (self.query((PieceType::King, by)) & get_king_attacks(square))
+            | (bishops & get_bishop_attacks(square, self.occupancy()))
+            | (rooks & get_rook_attacks(square, self.occupancy()))
+    }
 
-        // Look for rook & queen checkers.
-        let rook_check_locations = get_rook_attacks(king_sq, self.occupancy());
-        self.checkers |= (self.query((PieceType::Rook, them))
-            | self.query((PieceType::Queen, them)))
-            & rook_check_locations;
+    /// Gets a [`BitBoard`] of the pieces defending the piece on a [`Square`].
+    ///
+    /// The defenders are the pieces of the same [`Color`] as the piece on the [`Square`] that
+    /// attack it. If the [`Square`] is empty, an empty [`BitBoard`] is returned.
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::{BitBoard, ChessBoard, Color, Square};
+    ///
+    /// // Create a chess board.
+    /// let board = ChessBoard::from_fen("4k3/8/8/3p4/4p3/2N5/8/4K3 w - -").unwrap();
+    ///
+    /// // The pawn on d5 defends the pawn on e4, which the knight attacks.
+    /// assert_eq!(board.defenders(Square::E4), Square::D5.bitboard());
+    ///
+    /// // The knight is undefended.
+    /// assert!(board.defenders(Square::C3).is_empty());
+    /// ```
+    #[inline]
+    pub fn defenders(&self, square: Square) -> BitBoard {
+        match self.piece_at(square) {
+            Some(piece) => self.attackers_to(square, piece.color),
+            None => BitBoard::EMPTY,
+        }
+    }
+
+    /// Gets a [`BitBoard`] of the pieces checking the king of the given [`Color`].
+    ///
+    /// Unlike [`ChessBoard::checkers`], this works for either [`Color`], not only the side to
+    /// move.
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::{ChessBoard, Color, Square};
+    ///
+    /// // Create a chess board where black is in check.
+    /// let board = ChessBoard::from_fen("4k3/8/8/8/8/8/4Q3/4K3 b - -").unwrap();
+    ///
+    /// assert_eq!(board.checkers_against(Color::Black), Square::E2.bitboard());
+    /// assert!(board.checkers_against(Color::White).is_empty());
+    /// ```
+    #[inline]
+    pub fn checkers_against(&self, color: Color) -> BitBoard {
+        self.attackers_to(self.get_king_square(color), !color)
     }
 
     /// Returns `true` if the given [`Square`] is attacked by the given [`Color`].
@@ -827,24 +1332,339 @@ impl ChessBoard {
         false
     }
 
+    /// Gets the [`Square`] of the least valuable piece of the given [`Color`] attacking a
+    /// [`Square`].
+    ///
+    /// Pieces are valued from pawn to king in the usual order. If nothing attacks the
+    /// [`Square`], a `None` value is returned.
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::{ChessBoard, Color, Square};
+    ///
+    /// // Create a chess board.
+    /// let board = ChessBoard::from_fen("4k3/8/8/3p4/8/2N2Q2/8/4K3 w - -").unwrap();
+    ///
+    /// // The knight and queen attack d5, but the knight is worth less.
+    /// assert_eq!(board.smallest_attacker(Square::D5, Color::White), Some(Square::C3));
+    ///
+    /// // Nothing attacks a6.
+    /// assert_eq!(board.smallest_attacker(Square::A6, Color::White), None);
+    /// ```
+    #[inline]
+    pub fn smallest_attacker(&self, square: Square, by: Color) -> Option<Square> {
+        let bishop_attacks = get_bishop_attacks(square, self.occupancy());
+        let rook_attacks = get_rook_attacks(square, self.occupancy());
+
+        let attackers = [
+            (PieceType::Pawn, get_pawn_attacks(square, !by)),
+            (PieceType::Knight, get_knight_attacks(square)),
+            (PieceType::Bishop, bishop_attacks),
+            (PieceType::Rook, rook_attacks),
+            (PieceType::Queen, bishop_attacks | rook_attacks),
+            (PieceType::King, get_king_attacks(square)),
+        ];
+
+        attackers
+            .into_iter()
+            .find_map(|(kind, locations)| (self.query((kind, by)) & locations).b_scan_forward())
+    }
+
+    /// Gets the [`Square`] of the least valuable piece on the given squares.
+    ///
+    /// Pieces of both colors are considered, and valued from pawn to king in the usual order. If
+    /// there are no pieces on the squares, a `None` value is returned.
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::{BitBoard, ChessBoard, Square};
+    ///
+    /// // Create a chess board.
+    /// let board = ChessBoard::from_fen("4k3/8/8/3r4/8/2N2Q2/8/4K3 w - -").unwrap();
+    ///
+    /// let squares = BitBoard::from_squares(&[Square::C3, Square::D5, Square::F3]);
+    /// assert_eq!(board.least_valuable(squares), Some(Square::C3));
+    /// assert_eq!(board.least_valuable(BitBoard::from_square(Square::A6)), None);
+    /// ```
+    #[inline]
+    pub fn least_valuable(&self, squares: BitBoard) -> Option<Square> {
+        [
+            PieceType::Pawn,
+            PieceType::Knight,
+            PieceType::Bishop,
+            PieceType::Rook,
+            PieceType::Queen,
+            PieceType::King,
+        ]
+        .into_iter()
+        .find_map(|kind| (self.piece_occupancy(kind) & squares).b_scan_forward())
+    }
+
+    /// Gets the [`Square`] of the most valuable piece on the given squares.
+    ///
+    /// Pieces of both colors are considered, and valued from pawn to king in the usual order. If
+    /// there are no pieces on the squares, a `None` value is returned.
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::{BitBoard, ChessBoard, Color, Square};
+    ///
+    /// // Create a chess board.
+    /// let board = ChessBoard::from_fen("4k3/8/8/3r4/8/2N2Q2/8/4K3 w - -").unwrap();
+    ///
+    /// // Find the most valuable black piece the knight attacks.
+    /// let targets = BitBoard::from_squares(&[Square::D5, Square::E4, Square::B5]);
+    /// let targets = targets & board.color_occupancy(Color::Black);
+    /// assert_eq!(board.most_valuable(targets), Some(Square::D5));
+    /// ```
+    #[inline]
+    pub fn most_valuable(&self, squares: BitBoard) -> Option<Square> {
+        [
+            PieceType::King,
+            PieceType::Queen,
+            PieceType::Rook,
+            PieceType::Bishop,
+            PieceType::Knight,
+            PieceType::Pawn,
+        ]
+        .into_iter()
+        .find_map(|kind| (self.piece_occupancy(kind) & squares).b_scan_forward())
+    }
+
+    /// Gets a [`BitBoard`] of the squares the king of the given [`Color`] can step to.
+    ///
+    /// A flight square is next to the king, not occupied by a friendly piece, and not attacked
+    /// by the enemy once the king has left its square. Castling is not included. This works for
+    /// either [`Color`], not only the side to move.
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::{ChessBoard, Color, Square};
+    ///
+    /// // Create a chess board where the rook on a8 guards the black king's rank.
+    /// let board = ChessBoard::from_fen("R5k1/5ppp/8/8/8/8/8/6K1 b - -").unwrap();
+    ///
+    /// // The black king has nowhere to go.
+    /// assert!(board.king_flight_squares(Color::Black).is_empty());
+    ///
+    /// // The white king can step to the second rank or sideways.
+    /// assert_eq!(board.king_flight_squares(Color::White).popcnt(), 5);
+    /// assert!(board.king_flight_squares(Color::White).contains(Square::H2));
+    /// ```
+    #[inline]
+    pub fn king_flight_squares(&self, color: Color) -> BitBoard {
+        generate_king_flight_squares(self, color)
+    }
+
+    /// Gets a [`BitBoard`] of every [`Square`] attacked by the given [`Color`].
+    ///
+    /// Pawns attack diagonally whether or not there is a piece to capture, and pawn pushes are not
+    /// included.
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::{BitBoard, ChessBoard, Color, Square};
+    ///
+    /// // Create a chess board.
+    /// let board = ChessBoard::from_fen("4k3/8/8/8/8/8/4P3/4K3 w - -").unwrap();
+    ///
+    /// let attacks = board.attacks_by(Color::White);
+    /// assert!(attacks.contains(Square::D3));
+    /// assert!(attacks.contains(Square::F2));
+    /// assert!(!attacks.contains(Square::E3));
+    /// ```
+    #[inline]
+    pub fn attacks_by(&self, color: Color) -> BitBoard {
+        let mut attacks = BitBoard::EMPTY;
+
+        for square in self.query((PieceType::Pawn, color)) {
+            attacks |= get_pawn_attacks(square, color);
+        }
+
+        for square in self.query((PieceType::Knight, color)) {
+            attacks |= get_knight_attacks(square);
+        }
+
+        for square in self.query((PieceType::Bishop, color)) | self.query((PieceType::Queen, color))
+        {
+            attacks |= get_bishop_attacks(square, self.occupancy());
+        }
+
+        for square in self.query((PieceType::Rook, color)) | self.query((PieceType::Queen, color)) {
+            attacks |= get_rook_attacks(square, self.occupancy());
+        }
+
+        attacks | get_king_attacks(self.get_king_square(color))
+    }
+
+    /// Gets the [`Square`]s of a [`BitBoard`] that are attacked by the given [`Color`].
+    ///
+    /// This gives the same squares as calling [`ChessBoard::is_attacked`] on each square, but the
+    /// attacks of the color are only calculated once.
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::{BitBoard, ChessBoard, Color, Square};
+    ///
+    /// // Create a chess board.
+    /// let board = ChessBoard::from_fen("4k3/8/8/8/8/8/8/4K2R w - -").unwrap();
+    ///
+    /// // Only two of these squares are attacked by the rook.
+    /// let squares = BitBoard::from_squares(&[Square::G1, Square::G2, Square::H2]);
+    /// assert_eq!(
+    ///     board.attacked_squares(squares, Color::White),
+    ///     BitBoard::from_squares(&[Square::G1, Square::H2])
+    /// );
+    /// ```
+    #[inline]
+    pub fn attacked_squares(&self, squares: BitBoard, by: Color) -> BitBoard {
+        squares & self.attacks_by(by)
+    }
+
+    /// Sums the material of the given [`Color`] using the given [`PieceValues`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::{ChessBoard, Color, PieceValues};
+    ///
+    /// let board = ChessBoard::new();
+    /// assert_eq!(board.material_with(&PieceValues::DEFAULT, Color::White), 8 * 100 + 2 * 320 + 2 * 330 + 2 * 500 + 900);
+    ///
+    /// // Tuning tools can use their own values.
+    /// let values = PieceValues { pawn: 1, knight: 3, bishop: 3, rook: 5, queen: 9 };
+    /// assert_eq!(board.material_with(&values, Color::Black), 39);
+    /// ```
+    #[inline]
+    pub fn material_with(&self, values: &PieceValues, color: Color) -> i32 {
+        [
+            PieceType::Pawn,
+            PieceType::Knight,
+            PieceType::Bishop,
+            PieceType::Rook,
+            PieceType::Queen,
+        ]
+        .into_iter()
+        .map(|kind| values.value(kind) * self.query((kind, color)).popcnt() as i32)
+        .sum()
+    }
+
+    /// Gets the difference in piece counts between white and black.
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::ChessBoard;
+    ///
+    /// // White has a knight for black's bishop.
+    /// let board = ChessBoard::from_fen("4k3/pp6/2b5/8/8/8/PP6/1N2K3 w - -").unwrap();
+    /// let imbalance = board.imbalance();
+    /// assert_eq!((imbalance.knight, imbalance.bishop), (1, -1));
+    /// assert_eq!(imbalance.to_string(), "+1 knight, -1 bishop");
+    /// ```
+    #[inline]
+    pub fn imbalance(&self) -> Imbalance {
+        let difference = |kind| {
+            let white = self.query((kind, Color::White)).popcnt() as i8;
+            let black = self.query((kind, Color::Black)).popcnt() as i8;
+            white - black
+        };
+        Imbalance {
+            pawn: difference(PieceType::Pawn),
+            knight: difference(PieceType::Knight),
+            bishop: difference(PieceType::Bishop),
+            rook: difference(PieceType::Rook),
+            queen: difference(PieceType::Queen),
+        }
+    }
+
+    /// Estimates the game phase from the non-pawn material left on the [`ChessBoard`].
+    ///
+    /// Knights and bishops weigh 1, rooks 2 and queens 4, for a total of 24 in the starting
+    /// position. The result goes from 24 in the opening down to 0 in a pawn endgame, and is
+    /// capped at 24 when promotions add extra material.
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::ChessBoard;
+    ///
+    /// assert_eq!(ChessBoard::new().phase(), 24);
+    ///
+    /// // Only a rook and a knight are left.
+    /// let board = ChessBoard::from_fen("4k3/pp6/8/8/8/8/6PP/RN2K3 w - -").unwrap();
+    /// assert_eq!(board.phase(), 3);
+    /// ```
+    #[inline]
+    pub fn phase(&self) -> u8 {
+        let minors =
+            self.piece_occupancy(PieceType::Knight) | self.piece_occupancy(PieceType::Bishop);
+        let phase = minors.popcnt()
+            + 2 * self.piece_occupancy(PieceType::Rook).popcnt()
+            + 4 * self.piece_occupancy(PieceType::Queen).popcnt();
+        phase.min(24)
+    }
+
+    /// Counts the doubled pawns of the given [`Color`].
+    ///
+    /// Every pawn beyond the first on a [`File`] counts as one doubled pawn, so three pawns on a
+    /// file count as two.
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::{ChessBoard, Color};
+    ///
+    /// // White has two pawns on the c file and three on the e file.
+    /// let board = ChessBoard::from_fen("4k3/8/4P3/4P3/2P5/2P1P3/8/4K3 w - -").unwrap();
+    /// assert_eq!(board.doubled_pawns(Color::White), 3);
+    /// assert_eq!(board.doubled_pawns(Color::Black), 0);
+    /// ```
+    #[inline]
+    pub fn doubled_pawns(&self, color: Color) -> u8 {
+        let pawns = self.query((PieceType::Pawn, color));
+        FILES
+            .iter()
+            .map(|file| {
+                (pawns & BitBoard::from_file(*file))
+                    .popcnt()
+                    .saturating_sub(1)
+            })
+            .sum()
+    }
+
     /// Inserts a new piece into the [`ChessBoard`].
     ///
     /// Note: This function assumes that there is not already a piece at the given [`Square`].
     fn insert(&mut self, square: Square, piece: impl Into<Piece>) {
         let piece = piece.into();
+        debug_assert!(
+            self.piece_at(square).is_none(),
+            "cannot insert a piece on occupied square {}",
+            square
+        );
+        self.material_hash
+            .material(piece, self.query(piece).popcnt());
+        if piece.kind == PieceType::Pawn {
+            self.pawn_hash.piece(square, piece);
+        }
         self.piece_bbs[piece.kind.index()] |= square.bitboard();
         self.color_bbs[piece.color.index()] |= square.bitboard();
         self.hash.piece(square, piece);
     }
 
-    /// Removes a piece from the [`ChessBoard`]
+    /// Removes a piece from the [`ChessBoard`] and returns it.
     ///
     /// Note: This function assumes there is a piece at the given [`Square`].
-    fn remove(&mut self, square: Square) {
-        let piece = self.piece_at(square).unwrap();
+    fn remove(&mut self, square: Square) -> Piece {
+        let piece = self
+            .piece_at(square)
+            .unwrap_or_else(|| panic!("cannot remove a piece from empty square {}", square));
         self.piece_bbs[piece.kind.index()] ^= square.bitboard();
         self.color_bbs[piece.color.index()] ^= square.bitboard();
         self.hash.piece(square, piece);
+        self.material_hash
+            .material(piece, self.query(piece).popcnt());
+        if piece.kind == PieceType::Pawn {
+            self.pawn_hash.piece(square, piece);
+        }
+        piece
     }
 
     /// Moves a piece from one square to another.
@@ -852,10 +1672,25 @@ impl ChessBoard {
     /// Note: This function assumes that there is a piece at the start square and that the end square is empty.
     fn move_piece(&mut self, start: Square, end: Square, piece: impl Into<Piece>) {
         let piece = piece.into();
+        debug_assert!(
+            self.piece_at(start) == Some(piece),
+            "expected {:?} on square {}",
+            piece,
+            start
+        );
+        debug_assert!(
+            self.piece_at(end).is_none(),
+            "cannot move a piece to occupied square {}",
+            end
+        );
         self.piece_bbs[piece.kind.index()] ^= start.bitboard() | end.bitboard();
         self.color_bbs[piece.color.index()] ^= start.bitboard() | end.bitboard();
         self.hash.piece(start, piece);
         self.hash.piece(end, piece);
+        if piece.kind == PieceType::Pawn {
+            self.pawn_hash.piece(start, piece);
+            self.pawn_hash.piece(end, piece);
+        }
     }
 
     /// Toggles the current turn.
@@ -885,19 +1720,215 @@ impl ChessBoard {
     }
 
     /// Sets the en passant square.
+    ///
+    /// The en passant file is only added to the hash if a pawn of the side to move can capture on
+    /// the square, so positions that only differ by an unusable en passant square share a hash.
     fn set_ep(&mut self, square: Square) {
         self.en_passant = Some(square);
-        self.hash.ep(square);
+        if self.ep_is_capturable() {
+            self.hash.ep(square);
+        }
     }
 
     /// Clears the en passant square.
+    ///
+    /// Note: This function assumes the pawns have not changed since the square was set.
     fn clear_ep(&mut self) {
         if let Some(square) = self.en_passant {
-            self.hash.ep(square);
+            if self.ep_is_capturable() {
+                self.hash.ep(square);
+            }
             self.en_passant = None;
         }
     }
 
+    /// Returns `true` if a pawn of the side to move attacks the en passant square.
+    ///
+    /// Whether the capture would be legal is not checked.
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::ChessBoard;
+    ///
+    /// // No black pawn can capture on e3.
+    /// let board = ChessBoard::from_str_moves(&["e2e4"]).unwrap();
+    /// assert!(!board.ep_is_capturable());
+    ///
+    /// // The black pawn on d4 can capture on e3.
+    /// let board = ChessBoard::from_fen("4k3/8/8/8/3pP3/8/8/4K3 b - e3").unwrap();
+    /// assert!(board.ep_is_capturable());
+    /// ```
+    #[inline]
+    pub fn ep_is_capturable(&self) -> bool {
+        match self.en_passant {
+            None => false,
+            Some(square) => get_pawn_attacks(square, !self.turn)
+                .overlaps(self.query((PieceType::Pawn, self.turn))),
+        }
+    }
+
+    /// Clears the en passant square if no pawn can capture on it.
+    ///
+    /// A [`ChessBoard`] keeps the en passant square after every double pawn push. Some engines
+    /// only set it when an en passant capture is possible, so this makes the fen and equality of
+    /// the [`ChessBoard`] match theirs. The [`ZobristHash`] already ignores en passant squares
+    /// that cannot be captured.
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::ChessBoard;
+    ///
+    /// // Make a double pawn push.
+    /// let mut board = ChessBoard::from_str_moves(&["e2e4"]).unwrap();
+    /// assert!(board.en_passant_sq().is_some());
+    ///
+    /// // No black pawn can capture on e3.
+    /// board.normalize_ep();
+    /// assert!(board.en_passant_sq().is_none());
+    /// ```
+    #[inline]
+    pub fn normalize_ep(&mut self) {
+        if !self.ep_is_capturable() {
+            self.clear_ep();
+        }
+    }
+
+    /// Returns `true` if two [`ChessBoard`]'s are the same position for play purposes.
+    ///
+    /// Unlike `==`, an en passant square only counts when an en passant capture is possible, so
+    /// a leftover en passant square after a double pawn push is ignored.
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::ChessBoard;
+    ///
+    /// let pushed = ChessBoard::from_str_moves(&["e2e4"]).unwrap();
+    /// let fen =
+    ///     ChessBoard::from_fen("rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq -").unwrap();
+    /// assert_ne!(pushed, fen);
+    /// assert!(pushed.position_eq(&fen));
+    /// ```
+    #[inline]
+    pub fn position_eq(&self, other: &Self) -> bool {
+        let capturable_ep = |board: &Self| board.en_passant.filter(|_| board.ep_is_capturable());
+        (self.piece_bbs == other.piece_bbs)
+            && (self.color_bbs == other.color_bbs)
+            && (self.turn == other.turn)
+            && (self.castling_rights == other.castling_rights)
+            && (capturable_ep(self) == capturable_ep(other))
+    }
+
+    /// Gets the squares whose pieces differ between two [`ChessBoard`]'s.
+    ///
+    /// Each entry holds the [`Square`], the piece on it in this [`ChessBoard`] and the piece on it
+    /// in the other [`ChessBoard`]. The entries are ordered by [`Square`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::{ChessBoard, MoveGen, Piece, Square};
+    ///
+    /// // Castling changes the squares of both the king and the rook.
+    /// let before = ChessBoard::from_fen("4k3/8/8/8/8/8/8/4K2R w K -").unwrap();
+    /// let mut after = before.clone();
+    /// after.make_move(MoveGen::create_str_move(&before, "e1g1").unwrap());
+    /// assert_eq!(
+    ///     before.diff(&after),
+    ///     vec![
+    ///         (Square::E1, Some(Piece::WHITE_KING), None),
+    ///         (Square::F1, None, Some(Piece::WHITE_ROOK)),
+    ///         (Square::G1, None, Some(Piece::WHITE_KING)),
+    ///         (Square::H1, Some(Piece::WHITE_ROOK), None),
+    ///     ]
+    /// );
+    /// ```
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn diff(&self, other: &ChessBoard) -> Vec<(Square, Option<Piece>, Option<Piece>)> {
+        let mut changed = BitBoard::EMPTY;
+        for (a, b) in self.piece_bbs.iter().zip(other.piece_bbs.iter()) {
+            changed |= *a ^ *b;
+        }
+        for (a, b) in self.color_bbs.iter().zip(other.color_bbs.iter()) {
+            changed |= *a ^ *b;
+        }
+
+        changed
+            .map(|square| (square, self.piece_at(square), other.piece_at(square)))
+            .collect()
+    }
+
+    /// Gets the canonical version of the [`ChessBoard`] under the board's symmetries.
+    ///
+    /// Positions that are the same up to a symmetry give the same canonical [`ChessBoard`], which
+    /// makes it suitable for indexing endgame tablebases. The [`Transform`] that was applied is
+    /// also returned, so moves on the canonical [`ChessBoard`] can be mapped back with
+    /// [`Transform::inverse`].
+    ///
+    /// Positions with castling rights are left as they are. Positions with pawns may only be
+    /// mirrored across the files, while positions without pawns may use any of the eight
+    /// symmetries of the board. The turn is never changed.
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::{ChessBoard, Transform};
+    ///
+    /// // The same endgame in two corners of the board.
+    /// let a = ChessBoard::from_fen("8/8/8/8/8/2k5/8/K6Q w - -").unwrap();
+    /// let b = ChessBoard::from_fen("Q6K/8/5k2/8/8/8/8/8 w - -").unwrap();
+    /// assert_eq!(a.canonicalize().0, b.canonicalize().0);
+    ///
+    /// // A position with castling rights is already canonical.
+    /// let board = ChessBoard::new();
+    /// assert_eq!(board.canonicalize(), (board, Transform::Identity));
+    /// ```
+    #[inline]
+    pub fn canonicalize(&self) -> (ChessBoard, Transform) {
+        let transforms = if !self.castling_rights.is_none_set() {
+            &Transform::ALL[..1]
+        } else if !self.piece_bbs[PieceType::Pawn.index()].is_empty() {
+            &Transform::ALL[..2]
+        } else {
+            &Transform::ALL[..]
+        };
+
+        // The first of the smallest boards is chosen, so symmetric positions stay as they are.
+        transforms
+            .iter()
+            .map(|&transform| (self.transformed(transform), transform))
+            .min_by_key(|(board, _)| {
+                (
+                    board.color_bbs.map(BitBoard::to_u64),
+                    board.piece_bbs.map(BitBoard::to_u64),
+                )
+            })
+            .unwrap()
+    }
+
+    /// Gets the [`ChessBoard`] with a [`Transform`] applied to every piece and the en passant
+    /// square.
+    ///
+    /// The castling rights are not transformed, so they must be empty unless the [`Transform`]
+    /// is [`Transform::Identity`].
+    fn transformed(&self, transform: Transform) -> ChessBoard {
+        if transform == Transform::Identity {
+            return self.clone();
+        }
+
+        let mut builder = BoardBuilder::new().turn(self.turn).unwrap();
+        for square in self.occupancy() {
+            builder = builder
+                .piece(transform.square(square), self.piece_at(square).unwrap())
+                .unwrap();
+        }
+        if let Some(square) = self.en_passant {
+            builder = builder.en_passant(transform.square(square)).unwrap();
+        }
+
+        let mut board = Self::from_builder(builder).unwrap();
+        board.half_move_clock = self.half_move_clock;
+        board
+    }
+
     /// Gets the piece at the given [`Square`].
     ///
     /// # Examples
@@ -945,6 +1976,40 @@ impl ChessBoard {
         Some(Piece::new(piece, color))
     }
 
+    /// Gets the piece on every [`Square`] of the [`ChessBoard`], indexed by [`Square::index`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::{ChessBoard, Piece, Square};
+    ///
+    /// // Create a new chess board.
+    /// let board = ChessBoard::new();
+    ///
+    /// let pieces = board.to_array();
+    /// assert_eq!(pieces[Square::E1.index()], Some(Piece::WHITE_KING));
+    /// assert_eq!(pieces[Square::D8.index()], Some(Piece::BLACK_QUEEN));
+    /// assert_eq!(pieces[Square::E4.index()], None);
+    /// ```
+    #[inline]
+    pub fn to_array(&self) -> [Option<Piece>; 64] {
+        let mut pieces = [None; 64];
+        for color in [Color::White, Color::Black] {
+            for kind in [
+                PieceType::Pawn,
+                PieceType::Knight,
+                PieceType::Bishop,
+                PieceType::Rook,
+                PieceType::Queen,
+                PieceType::King,
+            ] {
+                for square in self.query((kind, color)) {
+                    pieces[square.index()] = Some(Piece::new(kind, color));
+                }
+            }
+        }
+        pieces
+    }
+
     /// Gets a [`BitBoard`] containing the locations of all the pieces of a given piece type and color.
     #[inline]
     pub fn query(&self, piece: impl Into<Piece>) -> BitBoard {
@@ -958,11 +2023,58 @@ impl ChessBoard {
         self.color_occupancy(Color::White) | self.color_occupancy(Color::Black)
     }
 
-    /// Gets a [`BitBoard`] containing the locations of all the pieces of a given color.
+    /// Gets the [`BitBoard`] of all the pieces that would be on the [`ChessBoard`] after a [`Move`].
+    ///
+    /// This is the same as `get_child(mv).occupancy()`, but only toggles the squares the move
+    /// touches instead of making the move on a copy of the board.
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::{ChessBoard, MoveGen, Square};
+    ///
+    /// let board = ChessBoard::from_fen("4k3/8/8/3pP3/8/8/8/4K2R w K d6").unwrap();
+    ///
+    /// // The captured pawn is removed by en passant.
+    /// let mv = MoveGen::create_str_move(&board, "e5d6").unwrap();
+    /// assert!(!board.occupancy_after(mv).contains(Square::D5));
+    ///
+    /// // The rook moves when castling.
+    /// let mv = MoveGen::create_str_move(&board, "e1g1").unwrap();
+    /// assert!(board.occupancy_after(mv).contains(Square::F1));
+    /// assert!(!board.occupancy_after(mv).contains(Square::H1));
+    /// ```
     #[inline]
-    pub fn color_occupancy(&self, color: Color) -> BitBoard {
-        self.color_bbs[color.index()]
-    }
+    pub fn occupancy_after(&self, mv: Move) -> BitBoard {
+        let occupancy = self.occupancy();
+        match mv {
+            Move::Quiet { start, end, .. }
+            | Move::Capture { start, end, .. }
+            | Move::DoublePawnPush { start, end }
+            | Move::Promote { start, end, .. }
+            | Move::PromoteCapture { start, end, .. } => {
+                (occupancy & !start.bitboard()) | end.bitboard()
+            }
+            Move::Castle { start, end, side } => {
+                let (rook_start, rook_end) = Self::castle_rook_squares(self.turn, side);
+                let vacated = start.bitboard() | rook_start.bitboard();
+                (occupancy & !vacated) | end.bitboard() | rook_end.bitboard()
+            }
+            Move::EnPassant { start, end } => {
+                let captured = match self.turn {
+                    Color::White => end.down().unwrap(),
+                    Color::Black => end.up().unwrap(),
+                };
+                let vacated = start.bitboard() | captured.bitboard();
+                (occupancy & !vacated) | end.bitboard()
+            }
+        }
+    }
+
+    /// Gets a [`BitBoard`] containing the locations of all the pieces of a given color.
+    #[inline]
+    pub fn color_occupancy(&self, color: Color) -> BitBoard {
+        self.color_bbs[color.index()]
+    }
 
     /// Gets a [`BitBoard`] containing the locations of all the pieces of a given piece type.
     #[inline]
@@ -976,12 +2088,63 @@ impl ChessBoard {
         self.castling_rights.is_set(side, color)
     }
 
+    /// Gets all the castling rights, in the fen order of white kingside, white queenside, black
+    /// kingside and black queenside.
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::ChessBoard;
+    ///
+    /// assert_eq!(ChessBoard::new().castling_rights(), [true; 4]);
+    ///
+    /// let board = ChessBoard::from_fen("r3k3/8/8/8/8/8/8/4K2R w Kq -").unwrap();
+    /// assert_eq!(board.castling_rights(), [true, false, false, true]);
+    /// ```
+    #[inline]
+    pub fn castling_rights(&self) -> [bool; 4] {
+        [
+            self.castling_rights
+                .is_set(CastleSide::Kingside, Color::White),
+            self.castling_rights
+                .is_set(CastleSide::Queenside, Color::White),
+            self.castling_rights
+                .is_set(CastleSide::Kingside, Color::Black),
+            self.castling_rights
+                .is_set(CastleSide::Queenside, Color::Black),
+        ]
+    }
+
     /// Gets the square potentially targeted by en passant.
     #[inline]
     pub fn en_passant_sq(&self) -> Option<Square> {
         self.en_passant
     }
 
+    /// Gets the square of the pawn that could be captured by en passant.
+    ///
+    /// This is the square in front of [`ChessBoard::en_passant_sq`] from the side to move's
+    /// point of view, where the pawn that just made a double push stands.
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::{ChessBoard, Square};
+    ///
+    /// let board = ChessBoard::from_str_moves(&["e2e4"]).unwrap();
+    /// assert_eq!(board.en_passant_sq(), Some(Square::E3));
+    /// assert_eq!(board.en_passant_victim(), Some(Square::E4));
+    ///
+    /// let board = ChessBoard::from_str_moves(&["e2e4", "d7d5"]).unwrap();
+    /// assert_eq!(board.en_passant_victim(), Some(Square::D5));
+    /// ```
+    #[inline]
+    pub fn en_passant_victim(&self) -> Option<Square> {
+        let square = self.en_passant?;
+        match self.turn {
+            Color::White => square.down(),
+            Color::Black => square.up(),
+        }
+    }
+
     /// Gets the current turn.
     #[inline]
     pub fn turn(&self) -> Color {
@@ -995,6 +2158,10 @@ impl ChessBoard {
     }
 
     /// Gets the square of the king of a given [`Color`] on the [`ChessBoard`].
+    ///
+    /// # Panics
+    /// Panics if there is no king of the given [`Color`], which cannot happen on a valid
+    /// [`ChessBoard`]. Use [`ChessBoard::try_king_square`] for boards of uncertain provenance.
     #[inline]
     pub fn get_king_square(&self, color: Color) -> Square {
         self.query((PieceType::King, color))
@@ -1002,24 +2169,617 @@ impl ChessBoard {
             .unwrap()
     }
 
+    /// Gets the square of the king of a given [`Color`] on the [`ChessBoard`], if there is one.
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::{ChessBoard, Color, Square};
+    ///
+    /// let board = ChessBoard::new();
+    /// assert_eq!(board.try_king_square(Color::White), Some(Square::E1));
+    /// assert_eq!(board.try_king_square(Color::Black), Some(Square::E8));
+    /// ```
+    #[inline]
+    pub fn try_king_square(&self, color: Color) -> Option<Square> {
+        self.query((PieceType::King, color)).b_scan_forward()
+    }
+
     /// Gets the checkers.
     #[inline]
     pub fn checkers(&self) -> BitBoard {
         self.checkers
     }
 
+    /// Gets the [`CheckType`] of the side to move.
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::{CheckType, ChessBoard, Square};
+    ///
+    /// // The starting position has no checks.
+    /// assert_eq!(ChessBoard::new().check_type(), CheckType::None);
+    ///
+    /// // The black king is checked by the rook.
+    /// let board = ChessBoard::from_fen("4k3/8/8/8/8/8/8/K3R3 b - -").unwrap();
+    /// assert_eq!(board.check_type(), CheckType::Single(Square::E1));
+    ///
+    /// // The black king is checked by the rook and the knight.
+    /// let board = ChessBoard::from_fen("4k3/8/3N4/8/8/8/8/K3R3 b - -").unwrap();
+    /// assert_eq!(board.check_type(), CheckType::Double);
+    /// ```
+    #[inline]
+    pub fn check_type(&self) -> CheckType {
+        match self.checkers.popcnt() {
+            0 => CheckType::None,
+            1 => CheckType::Single(self.checkers.b_scan_forward().unwrap()),
+            _ => CheckType::Double,
+        }
+    }
+
+    /// Returns `true` if only the king of the side to move can move.
+    ///
+    /// This is the case in double check, since no single move of another piece can block or
+    /// capture two checkers at once. The king may still have no legal moves.
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::ChessBoard;
+    ///
+    /// // The black king is checked by the rook and the knight.
+    /// let board = ChessBoard::from_fen("4k3/8/3N4/8/8/8/8/K3R3 b - -").unwrap();
+    /// assert!(board.only_king_moves());
+    ///
+    /// // A single check can be blocked.
+    /// let board = ChessBoard::from_fen("4k3/8/8/8/8/8/8/K3R3 b - -").unwrap();
+    /// assert!(!board.only_king_moves());
+    /// ```
+    #[inline]
+    pub fn only_king_moves(&self) -> bool {
+        self.check_type() == CheckType::Double
+    }
+
+    /// Returns `true` if making the [`Move`] would put the opponent in check.
+    ///
+    /// Both direct checks and discovered checks are detected. The [`Move`] must be legal on the
+    /// [`ChessBoard`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::{ChessBoard, MoveGen};
+    ///
+    /// let board = ChessBoard::from_fen("4k3/8/8/8/8/8/4N3/4RK2 w - -").unwrap();
+    ///
+    /// // A direct check.
+    /// let mv = MoveGen::create_str_move(&board, "e2d4").unwrap();
+    /// assert!(board.gives_check(mv));
+    ///
+    /// // Moving the rook off the file does not check.
+    /// let mv = MoveGen::create_str_move(&board, "e1d1").unwrap();
+    /// assert!(!board.gives_check(mv));
+    /// ```
+    #[inline]
+    pub fn gives_check(&self, mv: Move) -> bool {
+        let us = self.turn;
+        let them = !self.turn;
+        let king_sq = self.get_king_square(them);
+
+        let mut rooks = self.query((PieceType::Rook, us)) | self.query((PieceType::Queen, us));
+        let mut bishops = self.query((PieceType::Bishop, us)) | self.query((PieceType::Queen, us));
+        let mut occupancy = self.occupancy();
+
+        // Get the piece that ends up on the end square.
+        let (start, end, kind) = match mv {
+            Move::Quiet { start, end, moving } | Move::Capture { start, end, moving } => {
+                (start, end, moving)
+            }
+            Move::DoublePawnPush { start, end } => (start, end, PieceType::Pawn),
+            Move::EnPassant { start, end } => {
+                // Remove the en-passanted pawn.
+                let en_passanted = match us {
+                    Color::White => end.down().unwrap(),
+                    Color::Black => end.up().unwrap(),
+                };
+                occupancy ^= en_passanted.bitboard();
+                (start, end, PieceType::Pawn)
+            }
+            Move::Promote { start, end, target } | Move::PromoteCapture { start, end, target } => {
+                (start, end, target)
+            }
+            Move::Castle { start, end, side } => {
+                // Move the rook, the king itself cannot give check.
+                let (rook_start, rook_end) = Self::castle_rook_squares(us, side);
+                let rook_bb = rook_start.bitboard();
+                let rook_end_bb = rook_end.bitboard();
+                rooks = rooks ^ rook_bb | rook_end_bb;
+                occupancy = occupancy ^ rook_bb | rook_end_bb;
+                (start, end, PieceType::King)
+            }
+        };
+
+        // Update the occupancy and sliders for the moved piece.
+        occupancy = occupancy ^ start.bitboard() | end.bitboard();
+        rooks &= !start.bitboard();
+        bishops &= !start.bitboard();
+        match kind {
+            PieceType::Pawn => {
+                if get_pawn_attacks(king_sq, them).contains(end) {
+                    return true;
+                }
+            }
+            PieceType::Knight => {
+                if get_knight_attacks(king_sq).contains(end) {
+                    return true;
+                }
+            }
+            PieceType::Bishop => bishops |= end.bitboard(),
+            PieceType::Rook => rooks |= end.bitboard(),
+            PieceType::Queen => {
+                rooks |= end.bitboard();
+                bishops |= end.bitboard();
+            }
+            PieceType::King => (),
+        }
+
+        // Look for direct and discovered slider checks.
+        get_rook_attacks(king_sq, occupancy).overlaps(rooks)
+            || get_bishop_attacks(king_sq, occupancy).overlaps(bishops)
+    }
+
+    /// Returns `true` if the [`Move`] captures on the square the last move captured on.
+    ///
+    /// Both moves must be captures. An en passant capture lands on the square behind the captured
+    /// pawn, so recapturing the pawn that captured en passant is still detected, while the square
+    /// of the pawn it removed is not treated as a capture square.
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::{ChessBoard, MoveGen};
+    ///
+    /// let mut board = ChessBoard::from_fen("4k3/8/2p5/3p4/4P3/8/8/4K3 w - -").unwrap();
+    /// let last = MoveGen::create_str_move(&board, "e4d5").unwrap();
+    /// board.make_move(last);
+    ///
+    /// let mv = MoveGen::create_str_move(&board, "c6d5").unwrap();
+    /// assert!(board.is_recapture(mv, last));
+    /// ```
+    #[inline]
+    pub fn is_recapture(&self, mv: Move, last: Move) -> bool {
+        mv.is_capture() && last.is_capture() && mv.end() == last.end()
+    }
+
     /// Gets a hash for the [`ChessBoard`].
     #[inline]
     pub fn hash(&self) -> ZobristHash {
         self.hash
     }
 
+    /// Gets the hash for the [`ChessBoard`] as a [`u64`], the same as `hash().to_u64()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::ChessBoard;
+    ///
+    /// let board = ChessBoard::new();
+    /// assert_eq!(board.hash_u64(), board.hash().to_u64());
+    /// ```
+    #[inline]
+    pub fn hash_u64(&self) -> u64 {
+        self.hash.to_u64()
+    }
+
+    /// Computes the hash of the [`ChessBoard`] from scratch.
+    ///
+    /// [`ChessBoard::hash`] is updated as moves are made, so this is only needed to check that
+    /// the updates are correct, for example when writing a custom make and unmake.
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::ChessBoard;
+    ///
+    /// let board = ChessBoard::from_str_moves(&["e2e4", "d7d5", "e4e5", "f7f5"]).unwrap();
+    /// assert_eq!(board.recompute_hash(), board.hash());
+    /// ```
+    #[inline]
+    pub fn recompute_hash(&self) -> ZobristHash {
+        let mut hash = ZobristHash::new();
+
+        for color in [Color::White, Color::Black] {
+            for kind in [
+                PieceType::Pawn,
+                PieceType::Knight,
+                PieceType::Bishop,
+                PieceType::Rook,
+                PieceType::Queen,
+                PieceType::King,
+            ] {
+                for square in self.query((kind, color)) {
+                    hash.piece(square, (kind, color));
+                }
+            }
+
+            for side in [CastleSide::Kingside, CastleSide::Queenside] {
+                if self.castling_rights.is_set(side, color) {
+                    hash.castle_right(side, color);
+                }
+            }
+        }
+
+        // The en passant file is only hashed when it can be captured.
+        if let Some(square) = self.en_passant {
+            if self.ep_is_capturable() {
+                hash.ep(square);
+            }
+        }
+
+        if self.turn == Color::Black {
+            hash.toggle_turn();
+        }
+
+        hash
+    }
+
+    /// Gets a key for the [`ChessBoard`] that also includes the half move clock.
+    ///
+    /// Unlike [`ChessBoard::hash`], positions that only differ in their progress towards the
+    /// fifty-move rule get different keys. This is useful as a transposition key when the
+    /// fifty-move state matters. Use [`ChessBoard::footprint`] for repetition detection.
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::ChessBoard;
+    ///
+    /// let fresh = ChessBoard::from_fen("7k/8/1r6/8/8/6R1/8/K7 w - - 0").unwrap();
+    /// let stale = ChessBoard::from_fen("7k/8/1r6/8/8/6R1/8/K7 w - - 90").unwrap();
+    /// assert_eq!(fresh.hash(), stale.hash());
+    /// assert_ne!(fresh.full_key(), stale.full_key());
+    /// ```
+    #[inline]
+    pub fn full_key(&self) -> u64 {
+        let mut key = self.hash;
+        key.halfmoves(self.half_move_clock);
+        key.to_u64()
+    }
+
+    /// Gets a hash of the pawn placement on the [`ChessBoard`].
+    ///
+    /// Only the squares and colors of the pawns affect this hash, which makes it suitable as a key
+    /// for caching pawn structure evaluations.
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::ChessBoard;
+    ///
+    /// // Knight moves do not change the pawn hash.
+    /// let board = ChessBoard::new();
+    /// let knight_moved = ChessBoard::from_str_moves(&["g1f3"]).unwrap();
+    /// assert_eq!(board.pawn_hash(), knight_moved.pawn_hash());
+    ///
+    /// // Pawn moves do.
+    /// let pawn_moved = ChessBoard::from_str_moves(&["e2e4"]).unwrap();
+    /// assert_ne!(board.pawn_hash(), pawn_moved.pawn_hash());
+    /// ```
+    #[inline]
+    pub fn pawn_hash(&self) -> u64 {
+        self.pawn_hash.to_u64()
+    }
+
+    /// Gets a hash of the material on the [`ChessBoard`].
+    ///
+    /// Only the number of pieces of each kind and color affect this hash, not where they are
+    /// placed, which makes it suitable as a key for caching material evaluations.
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::ChessBoard;
+    ///
+    /// // Two positions with the same pieces on different squares.
+    /// let a = ChessBoard::from_fen("4k3/8/8/3n4/8/8/3P4/4K3 w - -").unwrap();
+    /// let b = ChessBoard::from_fen("k7/8/8/8/1P6/8/8/1n3K2 b - -").unwrap();
+    /// assert_eq!(a.material_hash(), b.material_hash());
+    ///
+    /// // A position with different material.
+    /// let c = ChessBoard::from_fen("4k3/8/8/3b4/8/8/3P4/4K3 w - -").unwrap();
+    /// assert_ne!(a.material_hash(), c.material_hash());
+    /// ```
+    #[inline]
+    pub fn material_hash(&self) -> u64 {
+        self.material_hash.to_u64()
+    }
+
+    /// Gets the Polyglot key of the [`ChessBoard`].
+    ///
+    /// This is the hash used by Polyglot `.bin` opening books, which is made from its own
+    /// published random numbers and differs from [`ChessBoard::hash`]. As in Polyglot, the en
+    /// passant file is only included if a pawn of the side to move could capture en passant.
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::ChessBoard;
+    ///
+    /// assert_eq!(ChessBoard::new().polyglot_key(), 0x463B96181691FC9C);
+    ///
+    /// let board = ChessBoard::from_str_moves(&["e2e4"]).unwrap();
+    /// assert_eq!(board.polyglot_key(), 0x823C9B50FD114196);
+    /// ```
+    #[inline]
+    pub fn polyglot_key(&self) -> u64 {
+        let mut key = 0;
+
+        for color in [Color::White, Color::Black] {
+            for kind in [
+                PieceType::Pawn,
+                PieceType::Knight,
+                PieceType::Bishop,
+                PieceType::Rook,
+                PieceType::Queen,
+                PieceType::King,
+            ] {
+                // Polyglot puts the black piece of each kind before the white one.
+                let kind_index = kind.index() * 2 + (color == Color::White) as usize;
+                for square in self.query((kind, color)) {
+                    key ^= POLYGLOT_RANDOM[64 * kind_index + square.index()];
+                }
+            }
+        }
+
+        for (index, (side, color)) in [
+            (CastleSide::Kingside, Color::White),
+            (CastleSide::Queenside, Color::White),
+            (CastleSide::Kingside, Color::Black),
+            (CastleSide::Queenside, Color::Black),
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            if self.is_castle_right_set(side, color) {
+                key ^= POLYGLOT_RANDOM[768 + index];
+            }
+        }
+
+        if let Some(square) = self.en_passant {
+            if self.ep_is_capturable() {
+                key ^= POLYGLOT_RANDOM[772 + square.file().to_u8() as usize];
+            }
+        }
+
+        if self.turn == Color::White {
+            key ^= POLYGLOT_RANDOM[780];
+        }
+
+        key
+    }
+
     /// Gets the half move clock of the [`ChessBoard`].
+    ///
+    /// The clock stops counting at [`u8::MAX`].
     #[inline]
     pub fn halfmoves(&self) -> u8 {
         self.half_move_clock
     }
 
+    /// Returns `true` if a draw can be claimed under the fifty-move rule.
+    ///
+    /// The rule needs fifty moves by each side without a capture or pawn move, so a draw can be
+    /// claimed once the half move clock reaches 100.
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::ChessBoard;
+    ///
+    /// let board = ChessBoard::from_fen("7k/8/1r6/8/8/6R1/8/K7 w - - 99").unwrap();
+    /// assert!(!board.can_claim_fifty_move());
+    ///
+    /// let board = ChessBoard::from_fen("7k/8/1r6/8/8/6R1/8/K7 w - - 100").unwrap();
+    /// assert!(board.can_claim_fifty_move());
+    /// ```
+    #[inline]
+    pub fn can_claim_fifty_move(&self) -> bool {
+        self.half_move_clock >= 100
+    }
+
+    /// Returns `true` if the side to move is checkmated.
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::ChessBoard;
+    ///
+    /// // Create a chess board after fool's mate.
+    /// let board =
+    ///     ChessBoard::from_fen("rnb1kbnr/pppp1ppp/8/4p3/6Pq/5P2/PPPPP2P/RNBQKBNR w KQkq - 1 3")
+    ///         .unwrap();
+    /// assert!(board.is_checkmate());
+    ///
+    /// assert!(!ChessBoard::new().is_checkmate());
+    /// ```
+    #[inline]
+    pub fn is_checkmate(&self) -> bool {
+        !self.checkers.is_empty() && !MoveGen::has_legal_move(self)
+    }
+
+    /// Returns `true` if the side to move is not in check and has no legal moves.
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::ChessBoard;
+    ///
+    /// let board = ChessBoard::from_fen("1r5k/8/8/8/8/8/7r/K7 w - -").unwrap();
+    /// assert!(board.is_stalemate());
+    ///
+    /// assert!(!ChessBoard::new().is_stalemate());
+    /// ```
+    #[inline]
+    pub fn is_stalemate(&self) -> bool {
+        self.checkers.is_empty() && !MoveGen::has_legal_move(self)
+    }
+
+    /// Gets the [`TerminalState`] of the [`ChessBoard`] if the side to move has no legal moves.
+    ///
+    /// Unlike calling [`ChessBoard::is_checkmate`] and [`ChessBoard::is_stalemate`] one after the
+    /// other, the legal moves are only searched once.
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::{ChessBoard, Color, TerminalState};
+    ///
+    /// // Create a chess board after fool's mate.
+    /// let board =
+    ///     ChessBoard::from_fen("rnb1kbnr/pppp1ppp/8/4p3/6Pq/5P2/PPPPP2P/RNBQKBNR w KQkq - 1 3")
+    ///         .unwrap();
+    /// assert_eq!(
+    ///     board.terminal_state(),
+    ///     Some(TerminalState::Checkmate { loser: Color::White })
+    /// );
+    ///
+    /// let board = ChessBoard::from_fen("1r5k/8/8/8/8/8/7r/K7 w - -").unwrap();
+    /// assert_eq!(board.terminal_state(), Some(TerminalState::Stalemate));
+    ///
+    /// assert_eq!(ChessBoard::new().terminal_state(), None);
+    /// ```
+    #[inline]
+    pub fn terminal_state(&self) -> Option<TerminalState> {
+        if MoveGen::has_legal_move(self) {
+            None
+        } else if self.checkers.is_empty() {
+            Some(TerminalState::Stalemate)
+        } else {
+            Some(TerminalState::Checkmate { loser: self.turn })
+        }
+    }
+
+    /// Returns `true` if neither side has enough material to checkmate.
+    ///
+    /// This covers a lone king against a lone king, a king and a minor piece against a lone king,
+    /// and kings with bishops on squares of the same color.
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::ChessBoard;
+    ///
+    /// // Create a chess board with a king and knight against a king.
+    /// let board = ChessBoard::from_fen("7k/8/8/8/8/8/8/KN6 w - -").unwrap();
+    /// assert!(board.is_insufficient_material());
+    ///
+    /// // Create a chess board with a king and rook against a king.
+    /// let board = ChessBoard::from_fen("7k/8/8/8/8/8/8/KR6 w - -").unwrap();
+    /// assert!(!board.is_insufficient_material());
+    /// ```
+    #[inline]
+    pub fn is_insufficient_material(&self) -> bool {
+        let white = self.color_occupancy(Color::White).popcnt();
+        let black = self.color_occupancy(Color::Black).popcnt();
+        let minors = |color: Color| {
+            self.query(Piece::new(PieceType::Bishop, color))
+                | self.query(Piece::new(PieceType::Knight, color))
+        };
+
+        match (white, black) {
+            (1, 1) => true,
+            (1, 2) => !minors(Color::Black).is_empty(),
+            (2, 1) => !minors(Color::White).is_empty(),
+            (2, 2) => {
+                let white_bishop = self.query(Piece::WHITE_BISHOP);
+                let black_bishop = self.query(Piece::BLACK_BISHOP);
+                (white_bishop.overlaps(BitBoard::WHITE_SQUARES)
+                    && black_bishop.overlaps(BitBoard::WHITE_SQUARES))
+                    || (white_bishop.overlaps(BitBoard::BLACK_SQUARES)
+                        && black_bishop.overlaps(BitBoard::BLACK_SQUARES))
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns `true` if the given [`Color`] may still be able to checkmate.
+    ///
+    /// A lone king can never checkmate, and neither side can when
+    /// [`ChessBoard::is_insufficient_material`] holds. Other positions where no sequence of legal
+    /// moves leads to checkmate are not detected.
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::{ChessBoard, Color};
+    ///
+    /// // Create a chess board with a king against a king and queen.
+    /// let board = ChessBoard::from_fen("7k/8/8/8/8/8/8/KQ6 w - -").unwrap();
+    /// assert!(board.has_mating_material(Color::White));
+    /// assert!(!board.has_mating_material(Color::Black));
+    /// ```
+    #[inline]
+    pub fn has_mating_material(&self, color: Color) -> bool {
+        self.color_occupancy(color).more_than_one() && !self.is_insufficient_material()
+    }
+
+    /// Gets the [`GameResult`] of the [`ChessBoard`] if the game is over.
+    ///
+    /// Checkmate, stalemate, insufficient material and the fifty and seventy-five-move rules are
+    /// detected. A half move clock of 150 or more is reported as
+    /// [`DrawReason::SeventyFiveMoves`], and one of 100 or more as [`DrawReason::FiftyMoves`],
+    /// though a checkmate on the last move still stands.
+    ///
+    /// A [`DrawReason::FiftyMoves`] result only means a draw can be claimed, as
+    /// [`ChessBoard::can_claim_fifty_move`] reports. The game goes on until a player claims it,
+    /// which is how [`ChessGame::claimable_draw`](crate::ChessGame::claimable_draw) treats it, so
+    /// [`ChessGame::result`](crate::ChessGame::result) is still `None` for such a position.
+    ///
+    /// Repetitions cannot be detected from a single position, since they need the history of the
+    /// game. Use [`ChessGame`](crate::ChessGame) to track them.
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::{ChessBoard, DrawReason, GameResult};
+    ///
+    /// // Create a chess board after fool's mate.
+    /// let board =
+    ///     ChessBoard::from_fen("rnb1kbnr/pppp1ppp/8/4p3/6Pq/5P2/PPPPP2P/RNBQKBNR w KQkq - 1 3")
+    ///         .unwrap();
+    /// assert_eq!(board.outcome(), Some(GameResult::BlackWins));
+    ///
+    /// // Create a chess board where fifty moves were made without a capture or pawn move.
+    /// let board = ChessBoard::from_fen("7k/8/1r6/8/8/6R1/8/K7 w - - 100").unwrap();
+    /// assert_eq!(
+    ///     board.outcome(),
+    ///     Some(GameResult::Draw { reason: DrawReason::FiftyMoves })
+    /// );
+    ///
+    /// assert_eq!(ChessBoard::new().outcome(), None);
+    /// ```
+    #[inline]
+    pub fn outcome(&self) -> Option<GameResult> {
+        if self.is_insufficient_material() {
+            return Some(GameResult::Draw {
+                reason: DrawReason::InsufficientMaterial,
+            });
+        }
+
+        match self.terminal_state() {
+            Some(TerminalState::Checkmate {
+                loser: Color::White,
+            }) => return Some(GameResult::BlackWins),
+            Some(TerminalState::Checkmate {
+                loser: Color::Black,
+            }) => return Some(GameResult::WhiteWins),
+            Some(TerminalState::Stalemate) => {
+                return Some(GameResult::Draw {
+                    reason: DrawReason::Stalemate,
+                })
+            }
+            None => (),
+        }
+
+        if self.half_move_clock >= 150 {
+            return Some(GameResult::Draw {
+                reason: DrawReason::SeventyFiveMoves,
+            });
+        }
+
+        if self.can_claim_fifty_move() {
+            return Some(GameResult::Draw {
+                reason: DrawReason::FiftyMoves,
+            });
+        }
+
+        None
+    }
+
     /// Gets the [`Footprint`] of the [`ChessBoard`].
     #[inline]
     pub fn footprint(&self) -> Footprint {
@@ -1052,11 +2812,54 @@ impl Hash for ChessBoard {
     }
 }
 
+impl PartialOrd for ChessBoard {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ChessBoard {
+    /// Orders [`ChessBoard`]s by their hash, then by their position if the hashes are equal.
+    ///
+    /// The order is only meant to be deterministic, so boards can be sorted or kept in a
+    /// `BTreeMap`. It says nothing about the positions themselves, and may change if the hash keys
+    /// change.
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::ChessBoard;
+    /// use std::collections::BTreeSet;
+    ///
+    /// let boards = [
+    ///     ChessBoard::from_str_moves(&["e2e4"]).unwrap(),
+    ///     ChessBoard::new(),
+    ///     ChessBoard::from_str_moves(&["g1f3", "g8f6", "f3g1", "f6g8"]).unwrap(),
+    /// ];
+    ///
+    /// // The starting position is only stored once.
+    /// let set = boards.into_iter().collect::<BTreeSet<_>>();
+    /// assert_eq!(set.len(), 2);
+    /// ```
+    fn cmp(&self, other: &Self) -> Ordering {
+        let key = |board: &ChessBoard| {
+            (
+                board.hash.to_u64(),
+                board.piece_bbs.map(|bb| bb.to_u64()),
+                board.color_bbs.map(|bb| bb.to_u64()),
+                board.turn.index(),
+                board.castling_rights(),
+                board.en_passant.map(|sq| sq.as_u8()),
+            )
+        };
+        key(self).cmp(&key(other))
+    }
+}
+
 const ANSI_RESET_CODE: &str = "\x1b[0m";
 const ANSI_GRAY_CODE: &str = "\x1b[90m";
 
 impl Display for ChessBoard {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         writeln!(f, "{}  A B C D E F G H{}", ANSI_GRAY_CODE, ANSI_RESET_CODE)?;
         for rank in RANKS.into_iter().rev() {
             write!(
@@ -1080,9 +2883,188 @@ impl Display for ChessBoard {
     }
 }
 
+impl Move {
+    /// A [`Move`] for filling move buffers, like the one given to [`MoveGen::fill`].
+    ///
+    /// It moves a pawn from a1 to a1, so it is never a legal move.
+    pub const PLACEHOLDER: Move = Move::Quiet {
+        start: Square::A1,
+        end: Square::A1,
+        moving: PieceType::Pawn,
+    };
+
+    /// Formats the [`Move`] in UCI long algebraic notation.
+    ///
+    /// Unlike the [`Display`] implementation, which is meant for people to read, this format is
+    /// guaranteed to stay the same. Promotion pieces are lowercase, and castling moves are written
+    /// as the king's move.
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::{ChessBoard, MoveGen};
+    ///
+    /// let board = ChessBoard::from_fen("4k3/1P6/8/8/8/8/8/4K2R w K -").unwrap();
+    ///
+    /// let mv = MoveGen::create_str_move(&board, "e1g1").unwrap();
+    /// assert_eq!(mv.to_uci(), "e1g1");
+    ///
+    /// let mv = MoveGen::create_str_move(&board, "b7b8n").unwrap();
+    /// assert_eq!(mv.to_uci(), "b7b8n");
+    /// ```
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn to_uci(&self) -> String {
+        match *self {
+            Move::Quiet { start, end, .. }
+            | Move::Capture { start, end, .. }
+            | Move::Castle { start, end, .. }
+            | Move::DoublePawnPush { start, end }
+            | Move::EnPassant { start, end } => format!("{}{}", start, end),
+            Move::Promote { start, end, target } | Move::PromoteCapture { start, end, target } => {
+                format!("{}{}{}", start, end, target.to_char().to_ascii_lowercase())
+            }
+        }
+    }
+
+    /// Packs the [`Move`] into a [`u16`].
+    ///
+    /// The lowest 6 bits hold the start square, the next 6 bits hold the end square, and the top
+    /// 4 bits hold the promotion target (`0` for none, then `1` to `4` for a knight, bishop, rook
+    /// or queen).
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::{ChessBoard, Move, MoveGen};
+    ///
+    /// // Create a new chess board.
+    /// let board = ChessBoard::new();
+    ///
+    /// // Pack the move "e2e4" and unpack it again.
+    /// let mv = MoveGen::create_str_move(&board, "e2e4").unwrap();
+    /// let packed = mv.to_u16();
+    /// assert_eq!(Move::from_u16(&board, packed), Some(mv));
+    /// ```
+    #[inline]
+    pub const fn to_u16(&self) -> u16 {
+        let (start, end, flag) = match *self {
+            Move::Quiet { start, end, .. }
+            | Move::Capture { start, end, .. }
+            | Move::Castle { start, end, .. }
+            | Move::DoublePawnPush { start, end }
+            | Move::EnPassant { start, end } => (start, end, 0),
+            Move::Promote { start, end, target } | Move::PromoteCapture { start, end, target } => {
+                let flag = match target {
+                    PieceType::Knight => 1,
+                    PieceType::Bishop => 2,
+                    PieceType::Rook => 3,
+                    _ => 4,
+                };
+                (start, end, flag)
+            }
+        };
+
+        start.as_u8() as u16 | (end.as_u8() as u16) << 6 | flag << 12
+    }
+
+    /// Unpacks a [`Move`] made with [`Move::to_u16`] for the given [`ChessBoard`].
+    ///
+    /// Returns `None` if the packed move is not a legal move on the [`ChessBoard`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::{ChessBoard, Move, MoveGen, PieceType, Square};
+    ///
+    /// // Create a chess board.
+    /// let board = ChessBoard::from_fen("k7/3P4/8/8/8/8/8/K7 w - -").unwrap();
+    ///
+    /// // Pack an underpromotion and unpack it again.
+    /// let mv = MoveGen::create_str_move(&board, "d7d8n").unwrap();
+    /// assert_eq!(Move::from_u16(&board, mv.to_u16()), Some(mv));
+    ///
+    /// // The move "a1a3" is illegal.
+    /// let packed = Square::A1.as_u8() as u16 | (Square::A3.as_u8() as u16) << 6;
+    /// assert_eq!(Move::from_u16(&board, packed), None);
+    /// ```
+    #[inline]
+    pub fn from_u16(chessboard: &ChessBoard, packed: u16) -> Option<Move> {
+        let start = Square::from_u8((packed & 0x3F) as u8)?;
+        let end = Square::from_u8((packed >> 6 & 0x3F) as u8)?;
+        let target = match packed >> 12 {
+            0 => None,
+            1 => Some(PieceType::Knight),
+            2 => Some(PieceType::Bishop),
+            3 => Some(PieceType::Rook),
+            4 => Some(PieceType::Queen),
+            _ => return None,
+        };
+
+        let mv = MoveGen::create_promotion_move(
+            chessboard,
+            start,
+            end,
+            target.unwrap_or(PieceType::Queen),
+        )
+        .ok()?;
+
+        // The promotion flag must match whether the move is a promotion.
+        let is_promotion = matches!(mv, Move::Promote { .. } | Move::PromoteCapture { .. });
+        if is_promotion != target.is_some() {
+            return None;
+        }
+
+        Some(mv)
+    }
+
+    /// Gets the square the [`Move`] ends on.
+    ///
+    /// For castling this is the square the king ends on, and for en passant it is the square the
+    /// capturing pawn lands on rather than the square of the captured pawn.
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::{ChessBoard, MoveGen, Square};
+    ///
+    /// let board = ChessBoard::new();
+    /// let mv = MoveGen::create_str_move(&board, "g1f3").unwrap();
+    /// assert_eq!(mv.end(), Square::F3);
+    /// ```
+    #[inline]
+    pub const fn end(&self) -> Square {
+        match *self {
+            Move::Quiet { end, .. }
+            | Move::Capture { end, .. }
+            | Move::Castle { end, .. }
+            | Move::DoublePawnPush { end, .. }
+            | Move::EnPassant { end, .. }
+            | Move::Promote { end, .. }
+            | Move::PromoteCapture { end, .. } => end,
+        }
+    }
+
+    /// Returns `true` if the [`Move`] captures a piece, including en passant.
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::{ChessBoard, MoveGen};
+    ///
+    /// let board = ChessBoard::from_fen("4k3/8/8/3pP3/8/8/8/4K3 w - d6").unwrap();
+    /// assert!(MoveGen::create_str_move(&board, "e5d6").unwrap().is_capture());
+    /// assert!(!MoveGen::create_str_move(&board, "e5e6").unwrap().is_capture());
+    /// ```
+    #[inline]
+    pub const fn is_capture(&self) -> bool {
+        matches!(
+            self,
+            Move::Capture { .. } | Move::EnPassant { .. } | Move::PromoteCapture { .. }
+        )
+    }
+}
+
 impl Display for Move {
     /// Displays the [`Move`] in algebraic chess notation.
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    ///
+    /// This is meant for people to read and may change, use [`Move::to_uci`] for protocol output.
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         match self {
             Move::Quiet { start, end, .. }
             | Move::Capture { start, end, .. }
@@ -0,0 +1,107 @@
+use crate::defs::PieceType;
+use core::fmt::{Display, Formatter};
+
+/// The [`Imbalance`] struct holds the difference in piece counts between white and black.
+///
+/// It is made by [`ChessBoard::imbalance`](crate::ChessBoard::imbalance). Each count is the number
+/// of white pieces of a [`PieceType`] minus the number of black pieces, so a positive count means
+/// white has more of that piece. Kings are never imbalanced.
+///
+/// # Examples
+/// ```
+/// use rchess::ChessBoard;
+///
+/// // White has a rook for black's bishop, so white is up the exchange.
+/// let board = ChessBoard::from_fen("2b1k3/pp6/8/8/8/8/PP6/4KR2 w - -").unwrap();
+/// let imbalance = board.imbalance();
+/// assert_eq!((imbalance.rook, imbalance.bishop), (1, -1));
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Imbalance {
+    pub pawn: i8,
+    pub knight: i8,
+    pub bishop: i8,
+    pub rook: i8,
+    pub queen: i8,
+}
+
+impl Imbalance {
+    /// Gets the difference in the count of a [`PieceType`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::{ChessBoard, PieceType};
+    ///
+    /// let board = ChessBoard::from_fen("4k3/pp6/8/8/8/8/P7/4K3 w - -").unwrap();
+    /// assert_eq!(board.imbalance().count(PieceType::Pawn), -1);
+    /// assert_eq!(board.imbalance().count(PieceType::King), 0);
+    /// ```
+    #[inline]
+    pub const fn count(&self, kind: PieceType) -> i8 {
+        match kind {
+            PieceType::Pawn => self.pawn,
+            PieceType::Knight => self.knight,
+            PieceType::Bishop => self.bishop,
+            PieceType::Rook => self.rook,
+            PieceType::Queen => self.queen,
+            PieceType::King => 0,
+        }
+    }
+
+    /// Returns `true` if both colors have the same number of each piece.
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::ChessBoard;
+    ///
+    /// assert!(ChessBoard::new().imbalance().is_balanced());
+    /// ```
+    #[inline]
+    pub const fn is_balanced(&self) -> bool {
+        self.pawn == 0 && self.knight == 0 && self.bishop == 0 && self.rook == 0 && self.queen == 0
+    }
+}
+
+impl Display for Imbalance {
+    /// Writes the non-zero differences from white's point of view, such as `+1 knight, -1 bishop`,
+    /// or `balanced` if there are none.
+    ///
+    /// Only the counts of each piece are written. Trades are not named, so being up the exchange
+    /// is written as `-1 bishop, +1 rook` or `-1 knight, +1 rook`.
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::ChessBoard;
+    ///
+    /// let board = ChessBoard::from_fen("2b1k3/pppp4/8/8/8/8/PP6/4KR2 w - -").unwrap();
+    /// assert_eq!(board.imbalance().to_string(), "-2 pawns, -1 bishop, +1 rook");
+    ///
+    /// assert_eq!(ChessBoard::new().imbalance().to_string(), "balanced");
+    /// ```
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        if self.is_balanced() {
+            return write!(f, "balanced");
+        }
+
+        let mut first = true;
+        for (count, name) in [
+            (self.pawn, "pawn"),
+            (self.knight, "knight"),
+            (self.bishop, "bishop"),
+            (self.rook, "rook"),
+            (self.queen, "queen"),
+        ] {
+            if count == 0 {
+                continue;
+            }
+            if !first {
+                write!(f, ", ")?;
+            }
+            let plural = if count.abs() == 1 { "" } else { "s" };
+            write!(f, "{:+} {}{}", count, name, plural)?;
+            first = false;
+        }
+        Ok(())
+    }
+}
@@ -1,13 +1,23 @@
 mod builder;
 mod castling_rights;
 mod chessboard;
+mod imbalance;
 pub mod movegen;
-mod tables;
+mod piece_values;
+mod polyglot_random;
+pub(crate) mod tables;
+mod transform;
 pub mod zobrist;
 
 pub use builder::{BoardBuilder, BoardBuilderError};
 pub use chessboard::{
-    BuilderConversionError, ChessBoard, FenFormatError, FenLoadError, Footprint, Move,
+    BuilderConversionError, CheckType, ChessBoard, FenFormatError, FenLoadError, Footprint,
+    IllegalMoveError, Move, PositionError, StrMovesError,
 };
-pub use movegen::{MoveCreationError, MoveGen, StrMoveCreationError};
-pub use zobrist::ZobristHash;
+pub use imbalance::Imbalance;
+pub use movegen::{MoveCreationError, MoveGen, PerftStats, PieceMoves, StrMoveCreationError};
+#[cfg(feature = "std")]
+pub use movegen::{PerftMismatch, PerftSuiteError};
+pub use piece_values::PieceValues;
+pub use transform::Transform;
+pub use zobrist::{DynamicZobrist, ZobristHash};
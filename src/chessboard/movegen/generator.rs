@@ -18,7 +18,7 @@ pub fn generate_moves<const CAPTURES_ONLY: bool>(chessboard: &ChessBoard) -> Mov
         generate_bishop_moves::<CAPTURES_ONLY, false>(&mut moves, chessboard);
         generate_rook_moves::<CAPTURES_ONLY, false>(&mut moves, chessboard);
         generate_queen_moves::<CAPTURES_ONLY, false>(&mut moves, chessboard);
-    } else if chessboard.checkers().popcnt() == 1 {
+    } else if chessboard.checkers().is_single() {
         // The king is in check by one piece.
         generate_pawn_moves::<CAPTURES_ONLY, true>(&mut moves, chessboard);
         generate_knight_moves::<CAPTURES_ONLY, true>(&mut moves, chessboard);
@@ -34,6 +34,229 @@ pub fn generate_moves<const CAPTURES_ONLY: bool>(chessboard: &ChessBoard) -> Mov
     moves
 }
 
+/// Get a [`MoveList`] of pseudo-legal moves for a chessboard.
+///
+/// Pins and checks are ignored, so the moves may leave the king in check. Castling still follows
+/// the usual rules.
+#[cfg(feature = "std")]
+pub fn generate_pseudo_legal_moves(chessboard: &ChessBoard) -> MoveList {
+    let mut moves = MoveList::new();
+
+    // Get extra data about the chess board.
+    let us = chessboard.turn();
+    let occupancy = chessboard.occupancy();
+    let not_ours = !chessboard.color_occupancy(us);
+
+    for pawn_sq in chessboard.query((PieceType::Pawn, us)) {
+        let attacks = generate_pseudo_legal_pawn_attacks(chessboard, pawn_sq);
+        moves.push(PieceMoves::new(pawn_sq, attacks));
+    }
+
+    for knight_sq in chessboard.query((PieceType::Knight, us)) {
+        let attacks = get_knight_attacks(knight_sq) & not_ours;
+        moves.push(PieceMoves::new(knight_sq, attacks));
+    }
+
+    let king_sq = chessboard.get_king_square(us);
+    let mut attacks = get_king_attacks(king_sq) & not_ours;
+    if chessboard.checkers().is_empty() {
+        attacks |= generate_castle_moves::<false>(chessboard);
+    }
+    moves.push(PieceMoves::new(king_sq, attacks));
+
+    for bishop_sq in chessboard.query((PieceType::Bishop, us)) {
+        let attacks = get_bishop_attacks(bishop_sq, occupancy) & not_ours;
+        moves.push(PieceMoves::new(bishop_sq, attacks));
+    }
+
+    for rook_sq in chessboard.query((PieceType::Rook, us)) {
+        let attacks = get_rook_attacks(rook_sq, occupancy) & not_ours;
+        moves.push(PieceMoves::new(rook_sq, attacks));
+    }
+
+    for queen_sq in chessboard.query((PieceType::Queen, us)) {
+        let attacks = (get_bishop_attacks(queen_sq, occupancy)
+            | get_rook_attacks(queen_sq, occupancy))
+            & not_ours;
+        moves.push(PieceMoves::new(queen_sq, attacks));
+    }
+
+    moves
+}
+
+/// Generates the pawn attacks for a given square, ignoring pins and checks.
+#[cfg(feature = "std")]
+fn generate_pseudo_legal_pawn_attacks(chessboard: &ChessBoard, square: Square) -> BitBoard {
+    // Get extra data about the chess board.
+    let us = chessboard.turn();
+    let them = !chessboard.turn();
+
+    let en_passant_bb = match chessboard.en_passant_sq() {
+        None => BitBoard::EMPTY,
+        Some(sq) => sq.bitboard(),
+    };
+
+    let mut targets = BitBoard::EMPTY;
+
+    // Generate pawn non-captures.
+    let forward = match us {
+        Color::White => square.bitboard().up(),
+        Color::Black => square.bitboard().down(),
+    };
+
+    if !forward.overlaps(chessboard.occupancy()) {
+        targets |= forward;
+        if square.relative_to(us).rank() == Rank::Second {
+            let double = match us {
+                Color::White => forward.up(),
+                Color::Black => forward.down(),
+            };
+
+            if !double.overlaps(chessboard.occupancy()) {
+                targets |= double;
+            }
+        }
+    }
+
+    // Generate pawn captures, including en passant.
+    targets | get_pawn_attacks(square, us) & (chessboard.color_occupancy(them) | en_passant_bb)
+}
+
+/// Get a [`MoveList`] of the moves for a chessboard that are not generated when only captures are.
+pub fn generate_quiet_moves(chessboard: &ChessBoard) -> MoveList {
+    let mut quiets = MoveList::new();
+
+    // Every move is generated with the captures when in check.
+    if !chessboard.checkers().is_empty() {
+        return quiets;
+    }
+
+    let them = !chessboard.turn();
+    let pawns = chessboard.query((PieceType::Pawn, chessboard.turn()));
+    let en_passant_bb = match chessboard.en_passant_sq() {
+        None => BitBoard::EMPTY,
+        Some(sq) => sq.bitboard(),
+    };
+
+    let mut moves = generate_moves::<false>(chessboard);
+    while let Some(piece_moves) = moves.pop() {
+        // Remove the captures, including en passant.
+        let mut captures = chessboard.color_occupancy(them);
+        if pawns.contains(piece_moves.location) {
+            captures |= en_passant_bb;
+        }
+
+        quiets.push(PieceMoves::new(
+            piece_moves.location,
+            piece_moves.targets & !captures,
+        ));
+    }
+
+    quiets
+}
+
+/// Counts the moves for a chessboard without building a [`MoveList`].
+pub fn generate_count<const CAPTURES_ONLY: bool>(chessboard: &ChessBoard) -> u32 {
+    if chessboard.checkers().is_empty() {
+        // The king is not in check.
+        count_moves::<CAPTURES_ONLY, false>(chessboard)
+    } else if chessboard.checkers().is_single() {
+        // The king is in check by one piece.
+        count_moves::<CAPTURES_ONLY, true>(chessboard)
+    } else {
+        // The king is checked by multiple pieces.
+        let king_sq = chessboard.get_king_square(chessboard.turn());
+        generate_king_attacks::<false, true>(chessboard, king_sq).popcnt() as u32
+    }
+}
+
+/// Returns `true` if a chessboard has a legal move, stopping at the first piece that can move.
+pub fn generate_has_moves(chessboard: &ChessBoard) -> bool {
+    if chessboard.checkers().is_empty() {
+        // The king is not in check.
+        has_moves::<false>(chessboard)
+    } else if chessboard.checkers().is_single() {
+        // The king is in check by one piece.
+        has_moves::<true>(chessboard)
+    } else {
+        // The king is checked by multiple pieces.
+        let king_sq = chessboard.get_king_square(chessboard.turn());
+        !generate_king_attacks::<false, true>(chessboard, king_sq).is_empty()
+    }
+}
+
+/// Returns `true` if any piece of a chessboard can move.
+fn has_moves<const IN_CHECK: bool>(chessboard: &ChessBoard) -> bool {
+    // Get extra data about the chess board.
+    let us = chessboard.turn();
+
+    // The king is tried first, as it can usually move.
+    let king_sq = chessboard.get_king_square(us);
+    if !generate_king_attacks::<false, IN_CHECK>(chessboard, king_sq).is_empty() {
+        return true;
+    }
+
+    let knights = chessboard.query((PieceType::Knight, us)) & !chessboard.pinned();
+
+    chessboard
+        .query((PieceType::Pawn, us))
+        .any(|square| !generate_pawn_attacks::<false, IN_CHECK>(chessboard, square).is_empty())
+        || knights.into_iter().any(|square| {
+            !generate_knight_attacks::<false, IN_CHECK, true>(chessboard, square).is_empty()
+        })
+        || chessboard.query((PieceType::Bishop, us)).any(|square| {
+            !generate_bishop_attacks::<false, IN_CHECK>(chessboard, square).is_empty()
+        })
+        || chessboard
+            .query((PieceType::Rook, us))
+            .any(|square| !generate_rook_attacks::<false, IN_CHECK>(chessboard, square).is_empty())
+        || chessboard
+            .query((PieceType::Queen, us))
+            .any(|square| !generate_queen_attacks::<false, IN_CHECK>(chessboard, square).is_empty())
+}
+
+/// Counts the moves of every piece for a chessboard.
+fn count_moves<const CAPTURES_ONLY: bool, const IN_CHECK: bool>(chessboard: &ChessBoard) -> u32 {
+    // Get extra data about the chess board.
+    let us = chessboard.turn();
+    let promote_rank = BitBoard::from_rank(Rank::Eighth.relative_to(us));
+
+    let mut total = 0;
+
+    // Each pawn promotion counts as four moves.
+    for pawn_sq in chessboard.query((PieceType::Pawn, us)) {
+        let attacks = generate_pawn_attacks::<CAPTURES_ONLY, IN_CHECK>(chessboard, pawn_sq);
+        total += 4 * (attacks & promote_rank).popcnt() as u32;
+        total += (attacks & !promote_rank).popcnt() as u32;
+    }
+
+    for knight_sq in chessboard.query((PieceType::Knight, us)) & !chessboard.pinned() {
+        let attacks =
+            generate_knight_attacks::<CAPTURES_ONLY, IN_CHECK, true>(chessboard, knight_sq);
+        total += attacks.popcnt() as u32;
+    }
+
+    let king_sq = chessboard.get_king_square(us);
+    total += generate_king_attacks::<CAPTURES_ONLY, IN_CHECK>(chessboard, king_sq).popcnt() as u32;
+
+    for bishop_sq in chessboard.query((PieceType::Bishop, us)) {
+        let attacks = generate_bishop_attacks::<CAPTURES_ONLY, IN_CHECK>(chessboard, bishop_sq);
+        total += attacks.popcnt() as u32;
+    }
+
+    for rook_sq in chessboard.query((PieceType::Rook, us)) {
+        let attacks = generate_rook_attacks::<CAPTURES_ONLY, IN_CHECK>(chessboard, rook_sq);
+        total += attacks.popcnt() as u32;
+    }
+
+    for queen_sq in chessboard.query((PieceType::Queen, us)) {
+        let attacks = generate_queen_attacks::<CAPTURES_ONLY, IN_CHECK>(chessboard, queen_sq);
+        total += attacks.popcnt() as u32;
+    }
+
+    total
+}
+
 /// Gets a [`BitBoard`] of moves for the piece on the given [`Square`].
 ///
 /// If there was no piece on the square, or it is not that piece's turn, an empty [`BitBoard`] is returned.
@@ -50,7 +273,7 @@ pub fn generate_square_moves<const CAPTURES_ONLY: bool>(
         return BitBoard::EMPTY;
     }
 
-    if chessboard.checkers().popcnt() > 1 {
+    if chessboard.checkers().more_than_one() {
         if piece.kind != PieceType::King {
             return BitBoard::EMPTY;
         }
@@ -121,11 +344,6 @@ fn generate_pawn_attacks<const CAPTURES_ONLY: bool, const IN_CHECK: bool>(
         Some(sq) => sq.bitboard(),
     };
 
-    let start_rank = match chessboard.turn() {
-        Color::White => Rank::Second,
-        Color::Black => Rank::Seventh,
-    };
-
     let mut targets = BitBoard::EMPTY;
 
     // Look for pins.
@@ -145,7 +363,7 @@ fn generate_pawn_attacks<const CAPTURES_ONLY: bool, const IN_CHECK: bool>(
 
             if !forward.overlaps(chessboard.occupancy()) {
                 targets |= forward;
-                if square.rank() == start_rank {
+                if square.relative_to(us).rank() == Rank::Second {
                     let double = match us {
                         Color::White => forward.up(),
                         Color::Black => forward.down(),
@@ -314,12 +532,7 @@ fn generate_king_attacks<const CAPTURES_ONLY: bool, const IN_CHECK: bool>(
     };
 
     // Remove checked squares from king attacks.
-    for target in attacks {
-        let no_king_occupancy = chessboard.occupancy() ^ square.bitboard();
-        if is_square_attacked_with_occupancy(target, no_king_occupancy, them, chessboard) {
-            attacks ^= target.bitboard();
-        }
-    }
+    attacks = remove_attacked_king_targets(chessboard, square, attacks, them);
 
     // Add castle moves to king targets.
     if !CAPTURES_ONLY {
@@ -329,6 +542,31 @@ fn generate_king_attacks<const CAPTURES_ONLY: bool, const IN_CHECK: bool>(
     attacks
 }
 
+/// Gets the squares the king of the given color can step to without being attacked.
+///
+/// Castling is not included.
+pub fn generate_king_flight_squares(chessboard: &ChessBoard, color: Color) -> BitBoard {
+    let king_sq = chessboard.get_king_square(color);
+    let targets = get_king_attacks(king_sq) & !chessboard.color_occupancy(color);
+    remove_attacked_king_targets(chessboard, king_sq, targets, !color)
+}
+
+/// Removes the king targets that are attacked by the given color once the king has moved.
+fn remove_attacked_king_targets(
+    chessboard: &ChessBoard,
+    king_sq: Square,
+    mut targets: BitBoard,
+    them: Color,
+) -> BitBoard {
+    let no_king_occupancy = chessboard.occupancy() ^ king_sq.bitboard();
+    for target in targets {
+        if is_square_attacked_with_occupancy(target, no_king_occupancy, them, chessboard) {
+            targets ^= target.bitboard();
+        }
+    }
+    targets
+}
+
 /// Generates the bishop moves for a given [`ChessBoard`].
 fn generate_bishop_moves<const CAPTURES_ONLY: bool, const IN_CHECK: bool>(
     moves: &mut MoveList,
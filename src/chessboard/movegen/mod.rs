@@ -1,5 +1,10 @@
-mod generator;
+pub(super) mod generator;
 mod movegen;
 mod movelist;
+mod perft;
 
 pub use movegen::{MoveCreationError, MoveGen, StrMoveCreationError};
+pub use movelist::PieceMoves;
+pub use perft::PerftStats;
+#[cfg(feature = "std")]
+pub use perft::{PerftMismatch, PerftSuiteError};
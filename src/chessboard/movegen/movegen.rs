@@ -1,8 +1,15 @@
-use super::movelist::MoveList;
-use crate::chessboard::movegen::generator::{generate_moves, generate_square_moves};
+use super::movelist::{MoveList, PieceMoves};
+use super::perft::PerftStats;
+#[cfg(feature = "std")]
+use super::perft::{PerftMismatch, PerftSuiteError};
+#[cfg(feature = "std")]
+use crate::chessboard::movegen::generator::generate_pseudo_legal_moves;
+use crate::chessboard::movegen::generator::{
+    generate_count, generate_has_moves, generate_moves, generate_quiet_moves, generate_square_moves,
+};
 use crate::chessboard::{ChessBoard, Move};
 use crate::defs::*;
-use std::ops::Index;
+use core::ops::Index;
 use thiserror::Error;
 
 /// The [`StrMoveCreationError`] enum is the error type produced when creating moves.
@@ -23,13 +30,35 @@ pub enum StrMoveCreationError {
 pub struct MoveCreationError;
 
 /// The [`MoveGen`] struct generates moves for a [`ChessBoard`].
+///
+/// Cloning a [`MoveGen`] is cheap, and the clone yields the same remaining moves.
+///
+/// # Examples
+/// ```
+/// use rchess::{ChessBoard, MoveGen};
+///
+/// // Create a new chess board.
+/// let board = ChessBoard::new();
+///
+/// // Take a few moves, then snapshot the move generator.
+/// let mut moves = MoveGen::legal(&board);
+/// moves.next();
+/// moves.next();
+/// let snapshot = moves.clone();
+///
+/// assert_eq!(moves.collect::<Vec<_>>(), snapshot.collect::<Vec<_>>());
+/// ```
+#[derive(Clone)]
 pub struct MoveGen<'a> {
     chessboard: &'a ChessBoard,
     moves: MoveList,
     promote_status: Option<PromoteStatus>,
+    quiets_pending: bool,
+    underpromotions: bool,
 }
 
 /// The piece being promoted to.
+#[derive(Clone, Copy)]
 enum PromoteStatus {
     PromoteBishop,
     PromoteRook,
@@ -37,6 +66,12 @@ enum PromoteStatus {
 }
 
 impl<'a> MoveGen<'a> {
+    /// The most legal moves any chess position that passes [`ChessBoard::validate`] can have.
+    ///
+    /// Boards that [`ChessBoard::from_fen`] accepts but [`ChessBoard::validate`] rejects, such as
+    /// ones with more promoted pieces than missing pawns, can have more legal moves.
+    pub const MAX_LEGAL_MOVES: usize = 218;
+
     /// Creates a new [`MoveGen`] that generates all legal moves.
     ///
     /// # Examples
@@ -61,6 +96,41 @@ impl<'a> MoveGen<'a> {
             chessboard,
             moves,
             promote_status: None,
+            quiets_pending: false,
+            underpromotions: true,
+        }
+    }
+
+    /// Creates a new [`MoveGen`] that generates all legal moves, except promotions to a bishop or
+    /// rook.
+    ///
+    /// Promotions to a knight and a queen are still generated. This reduces the branching of a
+    /// search, but must not be used for perft, which counts every promotion.
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::{ChessBoard, Move, MoveGen, PieceType};
+    ///
+    /// // Create a chess board where white can promote.
+    /// let board = ChessBoard::from_fen("4k3/1P6/8/8/8/8/8/4K3 w - -").unwrap();
+    ///
+    /// let moves = MoveGen::legal_no_underpromotions(&board).collect::<Vec<_>>();
+    /// assert_eq!(moves.len(), MoveGen::count_legal_moves(&board) as usize - 2);
+    /// assert!(moves.iter().all(|mv| !matches!(
+    ///     mv,
+    ///     Move::Promote { target: PieceType::Bishop | PieceType::Rook, .. }
+    /// )));
+    /// ```
+    #[inline]
+    pub fn legal_no_underpromotions(chessboard: &'a ChessBoard) -> Self {
+        let moves = generate_moves::<false>(chessboard);
+
+        Self {
+            chessboard,
+            moves,
+            promote_status: None,
+            quiets_pending: false,
+            underpromotions: false,
         }
     }
 
@@ -93,6 +163,65 @@ impl<'a> MoveGen<'a> {
         generate_square_moves::<false>(chessboard, square)
     }
 
+    /// Gets the legal moves for a [`ChessBoard`] grouped by the [`Square`] of the moving piece.
+    ///
+    /// Each piece with at least one legal move gets a single [`PieceMoves`] entry. Unlike the
+    /// moves produced by [`MoveGen::legal`], promotions are not expanded into one move per
+    /// promotion target.
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::{ChessBoard, MoveGen, Square};
+    ///
+    /// // Create a chess board.
+    /// let board = ChessBoard::from_fen("k7/3P4/8/8/8/8/8/K7 w - -").unwrap();
+    ///
+    /// // Get the legal moves grouped by piece.
+    /// let grouped = MoveGen::grouped(&board);
+    /// assert_eq!(grouped.len(), 2);
+    ///
+    /// // The pawn's promotion only shows up once.
+    /// let pawn = grouped.iter().find(|moves| moves.location == Square::D7).unwrap();
+    /// assert_eq!(pawn.targets, Square::D8.bitboard());
+    /// ```
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn grouped(chessboard: &ChessBoard) -> Vec<PieceMoves> {
+        generate_moves::<false>(chessboard).to_vec()
+    }
+
+    /// Gets the pseudo-legal moves for a [`ChessBoard`].
+    ///
+    /// These moves ignore pins and checks, so they may leave the king in check: the king can move
+    /// onto an attacked square, pinned pieces can leave the pin, and checks do not have to be
+    /// answered. Castling still follows the usual rules. Each move must be checked for legality
+    /// before it is made.
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::{ChessBoard, Move, MoveGen, PieceType, Square};
+    ///
+    /// // Create a chess board where the knight on d2 is pinned by the bishop on b4.
+    /// let board = ChessBoard::from_fen("4k3/8/8/8/1b6/8/3N4/4K3 w - -").unwrap();
+    ///
+    /// // The pinned knight can move, even though it leaves the king in check.
+    /// let mv = Move::Quiet { start: Square::D2, end: Square::F3, moving: PieceType::Knight };
+    /// assert!(MoveGen::pseudo_legal(&board).contains(&mv));
+    /// assert!(!MoveGen::legal(&board).any(|legal| legal == mv));
+    /// ```
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn pseudo_legal(chessboard: &ChessBoard) -> Vec<Move> {
+        let movegen = MoveGen {
+            chessboard,
+            moves: generate_pseudo_legal_moves(chessboard),
+            promote_status: None,
+            quiets_pending: false,
+            underpromotions: true,
+        };
+        movegen.collect()
+    }
+
     /// Creates a new [`MoveGen`] that generates only capture moves and king-defending moves.
     ///
     /// # Examples
@@ -117,9 +246,116 @@ impl<'a> MoveGen<'a> {
             chessboard,
             moves,
             promote_status: None,
+            quiets_pending: false,
+            underpromotions: true,
+        }
+    }
+
+    /// Creates a new [`MoveGen`] that generates all legal moves, with captures first.
+    ///
+    /// The moves of [`MoveGen::captures_only`] are yielded first, then the remaining quiet moves.
+    /// The quiet moves are only generated once all the captures have been taken.
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::{ChessBoard, Move, MoveGen};
+    ///
+    /// // Create a chess board.
+    /// let board = ChessBoard::from_fen("4k3/6pp/8/8/8/8/8/4K1nR w - -").unwrap();
+    ///
+    /// // Get all the legal moves with captures first.
+    /// let moves = MoveGen::staged(&board).collect::<Vec<_>>();
+    /// assert_eq!(moves.len(), MoveGen::count_legal_moves(&board) as usize);
+    ///
+    /// // The two captures come first.
+    /// assert!(moves[..2].iter().all(|mv| matches!(mv, Move::Capture { .. })));
+    /// assert!(moves[2..].iter().all(|mv| !matches!(mv, Move::Capture { .. })));
+    /// ```
+    #[inline]
+    pub fn staged(chessboard: &'a ChessBoard) -> Self {
+        let moves = generate_moves::<true>(chessboard);
+
+        Self {
+            chessboard,
+            moves,
+            promote_status: None,
+            quiets_pending: true,
+            underpromotions: true,
         }
     }
 
+    /// Gets the legal non-capture moves that give check.
+    ///
+    /// Discovered checks and checking promotions are included.
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::{ChessBoard, MoveGen};
+    ///
+    /// // Create a chess board.
+    /// let board = ChessBoard::from_fen("7k/6pp/8/8/8/8/P7/K2R4 w - -").unwrap();
+    ///
+    /// // The only quiet check is the rook move to d8.
+    /// let checks = MoveGen::quiet_checks(&board);
+    /// assert_eq!(checks, vec![MoveGen::create_str_move(&board, "d1d8").unwrap()]);
+    /// ```
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn quiet_checks(chessboard: &ChessBoard) -> Vec<Move> {
+        // Quiet moves are generated with the captures when in check.
+        let moves = if chessboard.checkers().is_empty() {
+            generate_quiet_moves(chessboard)
+        } else {
+            generate_moves::<false>(chessboard)
+        };
+
+        let movegen = MoveGen {
+            chessboard,
+            moves,
+            promote_status: None,
+            quiets_pending: false,
+            underpromotions: true,
+        };
+
+        movegen
+            .filter(|mv| {
+                matches!(
+                    mv,
+                    Move::Quiet { .. }
+                        | Move::Castle { .. }
+                        | Move::DoublePawnPush { .. }
+                        | Move::Promote { .. }
+                )
+            })
+            .filter(|&mv| chessboard.gives_check(mv))
+            .collect()
+    }
+
+    /// Gets the legal capture moves that give check.
+    ///
+    /// En passant captures and capturing promotions are included.
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::{ChessBoard, MoveGen};
+    ///
+    /// // Create a chess board.
+    /// let board = ChessBoard::from_fen("4k3/8/8/8/4p3/8/8/n3R1K1 w - -").unwrap();
+    ///
+    /// // Both pieces can be captured, but only taking the pawn gives check.
+    /// let checks = MoveGen::checking_captures(&board);
+    /// assert_eq!(checks, vec![MoveGen::create_str_move(&board, "e1e4").unwrap()]);
+    /// ```
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn checking_captures(chessboard: &ChessBoard) -> Vec<Move> {
+        // King-defending quiet moves are also generated when in check.
+        MoveGen::captures_only(chessboard)
+            .filter(|mv| mv.is_capture())
+            .filter(|&mv| chessboard.gives_check(mv))
+            .collect()
+    }
+
     /// Gets a [`BitBoard`] of captures moves and king-defending moves for the [`Piece`] on the
     /// given [`Square`].
     ///
@@ -176,6 +412,7 @@ impl<'a> MoveGen<'a> {
     /// let move_vec = MoveGen::legal(&board).to_vec();
     /// assert_eq!(move_vec.len(), 20);
     /// ```
+    #[cfg(feature = "std")]
     #[inline]
     pub fn to_vec(self) -> Vec<Move> {
         let mut vec = Vec::with_capacity(self.count_moves() as usize);
@@ -183,6 +420,91 @@ impl<'a> MoveGen<'a> {
         vec
     }
 
+    /// Writes the moves of the [`MoveGen`] into a buffer without allocating.
+    ///
+    /// Returns the number of moves written. If the buffer is too small, the moves that did not fit
+    /// are left in the [`MoveGen`]. A buffer of [`MoveGen::MAX_LEGAL_MOVES`] moves holds every
+    /// legal move of a position that passes [`ChessBoard::validate`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::{ChessBoard, Move, MoveGen};
+    ///
+    /// // Create a new chess board.
+    /// let board = ChessBoard::new();
+    ///
+    /// // The buffer starts out filled with placeholders, which are overwritten.
+    /// let mut buffer = [Move::PLACEHOLDER; MoveGen::MAX_LEGAL_MOVES];
+    ///
+    /// let len = MoveGen::legal(&board).fill(&mut buffer);
+    /// assert_eq!(len, 20);
+    /// assert!(buffer[..len].iter().all(|mv| MoveGen::legal(&board).any(|legal| legal == *mv)));
+    /// ```
+    #[inline]
+    pub fn fill(&mut self, buffer: &mut [Move]) -> usize {
+        let mut len = 0;
+        for slot in buffer.iter_mut() {
+            match self.next() {
+                Some(mv) => *slot = mv,
+                None => break,
+            }
+            len += 1;
+        }
+        len
+    }
+
+    /// Collects the remaining moves of the [`MoveGen`], sorted by the given score from highest to
+    /// lowest.
+    ///
+    /// Moves with the same score stay in the order they were generated in.
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::{ChessBoard, MoveGen};
+    ///
+    /// // Create a chess board.
+    /// let board = ChessBoard::from_fen("4k3/6pp/8/8/8/8/8/4K1nR w - -").unwrap();
+    ///
+    /// // Put the captures first.
+    /// let moves = MoveGen::legal(&board).into_sorted_by(|mv| mv.is_capture() as i32);
+    /// assert!(moves[..2].iter().all(|mv| mv.is_capture()));
+    /// assert!(moves[2..].iter().all(|mv| !mv.is_capture()));
+    /// ```
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn into_sorted_by<F: FnMut(&Move) -> i32>(self, mut score: F) -> Vec<Move> {
+        let mut moves = self.to_vec();
+        moves.sort_by_cached_key(|mv| core::cmp::Reverse(score(mv)));
+        moves
+    }
+
+    /// Picks a uniformly random legal move on a [`ChessBoard`].
+    ///
+    /// Each promotion counts as its own move. No list of moves is built, since the number of legal
+    /// moves is known before they are generated. Returns `None` if there are no legal moves.
+    ///
+    /// The random number generator is the re-exported [`fastrand`](crate::fastrand).
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::{fastrand, ChessBoard, MoveGen};
+    ///
+    /// let mut rng = fastrand::Rng::with_seed(7);
+    ///
+    /// // Play a random playout.
+    /// let mut board = ChessBoard::new();
+    /// for _ in 0..20 {
+    ///     match MoveGen::random_legal(&board, &mut rng) {
+    ///         Some(mv) => board.make_move(mv),
+    ///         None => break,
+    ///     }
+    /// }
+    /// ```
+    #[inline]
+    pub fn random_legal(chessboard: &ChessBoard, rng: &mut fastrand::Rng) -> Option<Move> {
+        rng.choice(MoveGen::legal(chessboard))
+    }
+
     /// Returns `true` if no moves can be made on the [`ChessBoard`].
     ///
     /// # Examples
@@ -199,6 +521,29 @@ impl<'a> MoveGen<'a> {
     #[inline]
     pub fn is_empty(&self) -> bool {
         self.moves.is_empty()
+            && (!self.quiets_pending || generate_quiet_moves(self.chessboard).is_empty())
+    }
+
+    /// Returns `true` if a legal move can be made on the [`ChessBoard`].
+    ///
+    /// This stops as soon as a piece with a legal move is found, which makes it faster than
+    /// generating every move when checking for checkmate or stalemate.
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::{ChessBoard, MoveGen};
+    ///
+    /// // Create a chess board in stalemate.
+    /// let board = ChessBoard::from_fen("1r5k/8/8/8/8/8/7r/K7 w - -").unwrap();
+    /// assert!(!MoveGen::has_legal_move(&board));
+    ///
+    /// // Create a new chess board.
+    /// let board = ChessBoard::new();
+    /// assert!(MoveGen::has_legal_move(&board));
+    /// ```
+    #[inline]
+    pub fn has_legal_move(chessboard: &ChessBoard) -> bool {
+        generate_has_moves(chessboard)
     }
 
     /// Counts the number of legal moves for a given [`ChessBoard`].
@@ -216,8 +561,35 @@ impl<'a> MoveGen<'a> {
     /// ```
     #[inline]
     pub fn count_legal_moves(chessboard: &ChessBoard) -> u32 {
-        let moves = generate_moves::<false>(chessboard);
-        moves.count_moves(chessboard)
+        generate_count::<false>(chessboard)
+    }
+
+    /// Counts the number of legal moves for the [`Piece`] on the given [`Square`].
+    ///
+    /// Each promotion counts as four moves, one for each promotion target. If there was no
+    /// [`Piece`] on the given [`Square`], or it was not that [`Piece`]'s turn, `0` is returned.
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::{ChessBoard, MoveGen, Square};
+    ///
+    /// // Create a chess board.
+    /// let board = ChessBoard::from_fen("k7/3P4/8/8/8/8/8/K7 w - -").unwrap();
+    ///
+    /// // The pawn has one target square, but four moves.
+    /// assert_eq!(MoveGen::piece_legal(&board, Square::D7).popcnt(), 1);
+    /// assert_eq!(MoveGen::piece_move_count(&board, Square::D7), 4);
+    ///
+    /// // The king has three moves.
+    /// assert_eq!(MoveGen::piece_move_count(&board, Square::A1), 3);
+    /// ```
+    #[inline]
+    pub fn piece_move_count(chessboard: &ChessBoard, square: Square) -> u32 {
+        let targets = generate_square_moves::<false>(chessboard, square);
+        if targets.is_empty() {
+            return 0;
+        }
+        PieceMoves::new(square, targets).count_moves(chessboard, 4)
     }
 
     /// Counts the number of moves left in the [`MoveGen`].
@@ -235,7 +607,34 @@ impl<'a> MoveGen<'a> {
     /// ```
     #[inline]
     pub fn count_moves(&self) -> u32 {
-        self.moves.count_moves(self.chessboard)
+        let per_promotion = if self.underpromotions { 4 } else { 2 };
+
+        let mut total = self.moves.count_moves(self.chessboard, per_promotion);
+        if self.quiets_pending {
+            total +=
+                generate_quiet_moves(self.chessboard).count_moves(self.chessboard, per_promotion);
+        }
+
+        // Leave out the promotions of the current promotion square that were already yielded.
+        total
+            - match self.promote_status {
+                None => 0,
+                Some(PromoteStatus::PromoteBishop) => 1,
+                Some(PromoteStatus::PromoteRook) => 2,
+                Some(PromoteStatus::PromoteQueen) => per_promotion - 1,
+            }
+    }
+
+    /// Replaces the exhausted moves with the quiet moves of a staged [`MoveGen`].
+    ///
+    /// Returns `true` if there are new moves to yield.
+    fn load_quiets(&mut self) -> bool {
+        if !self.quiets_pending {
+            return false;
+        }
+        self.quiets_pending = false;
+        self.moves = generate_quiet_moves(self.chessboard);
+        !self.moves.is_empty()
     }
 
     /// Checks if a move with a given start and end [`Square`] is legal for a [`ChessBoard`].
@@ -262,6 +661,62 @@ impl<'a> MoveGen<'a> {
         sq_legal_moves.contains(end)
     }
 
+    /// Checks which of the candidate [`Move`]'s are legal on the [`ChessBoard`].
+    ///
+    /// The legal moves are only generated once, which makes this faster than checking each
+    /// candidate separately. A promotion is only legal if its target is a knight, bishop, rook or
+    /// queen.
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::{ChessBoard, Move, MoveGen, PieceType, Square};
+    ///
+    /// // Create a new chess board.
+    /// let board = ChessBoard::new();
+    ///
+    /// let candidates = [
+    ///     Move::DoublePawnPush { start: Square::E2, end: Square::E4 },
+    ///     Move::Quiet { start: Square::E2, end: Square::E4, moving: PieceType::Pawn },
+    ///     Move::Quiet { start: Square::G1, end: Square::F3, moving: PieceType::Knight },
+    /// ];
+    /// assert_eq!(MoveGen::filter_legal(&board, &candidates), vec![true, false, true]);
+    /// ```
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn filter_legal(chessboard: &ChessBoard, candidates: &[Move]) -> Vec<bool> {
+        // Index the legal targets by start square.
+        let mut targets = [BitBoard::EMPTY; 64];
+        for piece_moves in generate_moves::<false>(chessboard).to_vec() {
+            targets[piece_moves.location.index()] = piece_moves.targets;
+        }
+
+        candidates
+            .iter()
+            .map(|&mv| {
+                let (start, end, target) = match mv {
+                    Move::Quiet { start, end, .. }
+                    | Move::Capture { start, end, .. }
+                    | Move::Castle { start, end, .. }
+                    | Move::DoublePawnPush { start, end }
+                    | Move::EnPassant { start, end } => (start, end, PieceType::Queen),
+                    Move::Promote { start, end, target }
+                    | Move::PromoteCapture { start, end, target } => {
+                        if matches!(target, PieceType::Pawn | PieceType::King) {
+                            return false;
+                        }
+                        (start, end, target)
+                    }
+                };
+
+                // SAFETY: The move is legal if its start and end squares are.
+                targets[start.index()].contains(end)
+                    && unsafe {
+                        Self::create_promotion_move_unchecked(chessboard, start, end, target)
+                    } == mv
+            })
+            .collect()
+    }
+
     /// Attempts to create a [`Move`] from a start and end square.
     ///
     /// If the move is a promotion, it promotes to a queen.
@@ -292,6 +747,39 @@ impl<'a> MoveGen<'a> {
         Self::create_promotion_move(chessboard, start, end, PieceType::Queen)
     }
 
+    /// Attempts to create a [`Move`] from a start and end square, promoting to the given
+    /// [`PieceType`] if the move turns out to be a promotion.
+    ///
+    /// This is [`MoveGen::create_move`] with a different promotion than the queen. If the move is
+    /// illegal, or the promotion is a pawn or a king, a [`MoveCreationError`] is returned.
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::{ChessBoard, Move, MoveGen, PieceType, Square};
+    ///
+    /// // Create a chess board where white can promote.
+    /// let board = ChessBoard::from_fen("7k/5P2/8/8/8/8/8/4K3 w - -").unwrap();
+    ///
+    /// let mv = MoveGen::create_move_with_default(&board, Square::F7, Square::F8, PieceType::Knight);
+    /// assert_eq!(mv, Ok(Move::Promote { start: Square::F7, end: Square::F8, target: PieceType::Knight }));
+    ///
+    /// // Moves that are not promotions are created as usual.
+    /// let mv = MoveGen::create_move_with_default(&board, Square::E1, Square::E2, PieceType::Knight);
+    /// assert_eq!(mv, MoveGen::create_move(&board, Square::E1, Square::E2));
+    /// ```
+    #[inline]
+    pub fn create_move_with_default(
+        chessboard: &ChessBoard,
+        start: Square,
+        end: Square,
+        default_promotion: PieceType,
+    ) -> Result<Move, MoveCreationError> {
+        if matches!(default_promotion, PieceType::Pawn | PieceType::King) {
+            return Err(MoveCreationError);
+        }
+        Self::create_promotion_move(chessboard, start, end, default_promotion)
+    }
+
     /// Creates a [`Move`] from a given [`&str`] for the given [`ChessBoard`].
     ///
     /// # Examples
@@ -343,6 +831,176 @@ impl<'a> MoveGen<'a> {
         }
     }
 
+    /// Creates a [`Move`] from a given [`&str`] in standard algebraic notation (SAN) for the given
+    /// [`ChessBoard`].
+    ///
+    /// Check and annotation suffixes like `+`, `#`, `!` and `?` are ignored, and promotions may
+    /// be written with or without the `=`. Castling may be written with letters or zeros.
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::{ChessBoard, MoveGen};
+    ///
+    /// // Create a chess board where both white knights can reach d2.
+    /// let board = ChessBoard::from_fen("4k3/8/8/8/8/5N2/8/1N2K3 w - -").unwrap();
+    ///
+    /// let mv = MoveGen::create_san_move(&board, "Nbd2").unwrap();
+    /// assert_eq!(mv, MoveGen::create_str_move(&board, "b1d2").unwrap());
+    ///
+    /// // The move is ambiguous without the starting file.
+    /// assert!(MoveGen::create_san_move(&board, "Nd2").is_err());
+    /// ```
+    #[inline]
+    pub fn create_san_move(
+        chessboard: &ChessBoard,
+        san: &str,
+    ) -> Result<Move, StrMoveCreationError> {
+        let san = san.trim_end_matches(['+', '#', '!', '?']);
+        if !san.is_ascii() {
+            return Err(StrMoveCreationError::InvalidMove);
+        }
+
+        // Castling moves.
+        if let Ok(side) = san.parse::<CastleSide>() {
+            return MoveGen::legal(chessboard)
+                .find(|mv| matches!(mv, Move::Castle { side: castle, .. } if *castle == side))
+                .ok_or(StrMoveCreationError::IllegalMove(MoveCreationError));
+        }
+
+        // Get the moving piece.
+        let (moving, mut rest) = match san.as_bytes().first() {
+            Some(b'N') => (PieceType::Knight, &san[1..]),
+            Some(b'B') => (PieceType::Bishop, &san[1..]),
+            Some(b'R') => (PieceType::Rook, &san[1..]),
+            Some(b'Q') => (PieceType::Queen, &san[1..]),
+            Some(b'K') => (PieceType::King, &san[1..]),
+            _ => (PieceType::Pawn, san),
+        };
+
+        // Get the promotion target.
+        let mut target = None;
+        if moving == PieceType::Pawn {
+            let promotion = match rest.as_bytes().last() {
+                Some(b'N') => Some(PieceType::Knight),
+                Some(b'B') => Some(PieceType::Bishop),
+                Some(b'R') => Some(PieceType::Rook),
+                Some(b'Q') => Some(PieceType::Queen),
+                _ => None,
+            };
+            if promotion.is_some() {
+                target = promotion;
+                rest = rest[..rest.len() - 1].trim_end_matches('=');
+            }
+        }
+
+        // Get the end square, and the capture marker and disambiguation before it.
+        if rest.len() < 2 {
+            return Err(StrMoveCreationError::InvalidMove);
+        }
+        let end = Square::from_string(&rest[rest.len() - 2..])
+            .or(Err(StrMoveCreationError::InvalidMove))?;
+        let disambiguation = rest[..rest.len() - 2].trim_end_matches('x');
+        if disambiguation.len() > 2 {
+            return Err(StrMoveCreationError::InvalidMove);
+        }
+
+        let mut from = BitBoard::FULL;
+        for c in disambiguation.bytes() {
+            from &= match c {
+                b'a'..=b'h' => BitBoard::from_file(File::from_u8(c - b'a').unwrap()),
+                b'1'..=b'8' => BitBoard::from_rank(Rank::from_index(c - b'1').unwrap()),
+                _ => return Err(StrMoveCreationError::InvalidMove),
+            };
+        }
+
+        // Find the only legal move that matches.
+        let mut candidates = MoveGen::legal(chessboard).filter(|mv| {
+            let (start, mv_end, mv_target) = match *mv {
+                Move::Promote { start, end, target }
+                | Move::PromoteCapture { start, end, target } => (start, end, Some(target)),
+                Move::Quiet { start, end, .. }
+                | Move::Capture { start, end, .. }
+                | Move::DoublePawnPush { start, end }
+                | Move::EnPassant { start, end } => (start, end, None),
+                Move::Castle { .. } => return false,
+            };
+            mv_end == end
+                && mv_target == target
+                && from.contains(start)
+                && chessboard.piece_at(start).map(|piece| piece.kind) == Some(moving)
+        });
+
+        match (candidates.next(), candidates.next()) {
+            (Some(mv), None) => Ok(mv),
+            _ => Err(StrMoveCreationError::IllegalMove(MoveCreationError)),
+        }
+    }
+
+    /// Creates a [`Move`] from a given [`&str`] in Chess960 UCI notation for the given
+    /// [`ChessBoard`].
+    ///
+    /// In Chess960 notation, castling is written as the king moving onto its own rook (e.g.
+    /// `e1h1`). The standard notation of the king moving two squares is also accepted. Only the
+    /// standard king and rook squares are supported, as a [`ChessBoard`] cannot hold a Chess960
+    /// starting position.
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::{CastleSide, ChessBoard, Move, MoveGen, Square};
+    ///
+    /// // Create a chess board.
+    /// let board = ChessBoard::from_fen("4k3/8/8/8/8/8/8/R3K2R w KQ -").unwrap();
+    ///
+    /// // Castle by moving the king onto the rook.
+    /// let mv = MoveGen::create_uci_move_960(&board, "e1h1").unwrap();
+    /// assert_eq!(mv, Move::Castle { start: Square::E1, end: Square::G1, side: CastleSide::Kingside });
+    ///
+    /// // The standard notation still works.
+    /// assert_eq!(MoveGen::create_uci_move_960(&board, "e1g1"), Ok(mv));
+    /// ```
+    #[inline]
+    pub fn create_uci_move_960(
+        chessboard: &ChessBoard,
+        str: &str,
+    ) -> Result<Move, StrMoveCreationError> {
+        if str.len() == 4 {
+            let start = Square::from_string(str.index(0..=1));
+            let end = Square::from_string(str.index(2..=3));
+            if let (Ok(start), Ok(end)) = (start, end) {
+                if let Some(king_end) = Self::castle_king_end(chessboard, start, end) {
+                    return Ok(Self::create_move(chessboard, start, king_end)?);
+                }
+            }
+        }
+
+        Self::create_str_move(chessboard, str)
+    }
+
+    /// Gets the king's end square for a castle written as the king moving onto its own rook.
+    ///
+    /// Returns `None` if the start and end squares are not the king and rook squares for castling.
+    pub(crate) fn castle_king_end(
+        chessboard: &ChessBoard,
+        start: Square,
+        end: Square,
+    ) -> Option<Square> {
+        let us = chessboard.turn();
+        if start != Square::E1.relative_to(us)
+            || chessboard.piece_at(start) != Some(Piece::new(PieceType::King, us))
+            || chessboard.piece_at(end) != Some(Piece::new(PieceType::Rook, us))
+        {
+            return None;
+        }
+
+        if end == Square::H1.relative_to(us) {
+            Some(Square::G1.relative_to(us))
+        } else if end == Square::A1.relative_to(us) {
+            Some(Square::C1.relative_to(us))
+        } else {
+            None
+        }
+    }
+
     /// Attempts to create a [`Move`] from a start and end square.
     ///
     /// The move does not have to be a promotion, the `target` is what piece a pawn will promote to
@@ -419,12 +1077,10 @@ impl<'a> MoveGen<'a> {
 
         // Look for special pawn moves.
         if moving == PieceType::Pawn {
-            let (start_rank, double_rank, promote_rank) = match us {
-                Color::White => (Rank::Second, Rank::Fourth, Rank::Eighth),
-                Color::Black => (Rank::Seventh, Rank::Fifth, Rank::First),
-            };
+            let start_rank = start.relative_to(us).rank();
+            let end_rank = end.relative_to(us).rank();
             // Look for double pawn push.
-            if start.rank() == start_rank && end.rank() == double_rank {
+            if start_rank == Rank::Second && end_rank == Rank::Fourth {
                 return Move::DoublePawnPush { start, end };
             }
             // Look for en passant.
@@ -432,7 +1088,7 @@ impl<'a> MoveGen<'a> {
                 return Move::EnPassant { start, end };
             }
             // Look for promotion.
-            else if end.rank() == promote_rank {
+            else if start_rank == Rank::Seventh {
                 // Look for captures.
                 return if end.bitboard().overlaps(chessboard.color_occupancy(them)) {
                     Move::PromoteCapture { start, end, target }
@@ -443,10 +1099,9 @@ impl<'a> MoveGen<'a> {
         }
         // Look for castles.
         else if moving == PieceType::King {
-            let (castle_start, ks_end, qs_end) = match us {
-                Color::White => (Square::E1, Square::G1, Square::C1),
-                Color::Black => (Square::E8, Square::G8, Square::C8),
-            };
+            let castle_start = Square::E1.relative_to(us);
+            let ks_end = Square::G1.relative_to(us);
+            let qs_end = Square::C1.relative_to(us);
             if start == castle_start && end == ks_end {
                 return Move::Castle {
                     start,
@@ -471,6 +1126,7 @@ impl<'a> MoveGen<'a> {
     }
 
     /// Runs a debug perft on a given [`ChessBoard`], where the nodes for each move are printed.
+    #[cfg(feature = "std")]
     #[inline]
     pub fn debug_perft(chessboard: ChessBoard, depth: u8) {
         let movegen = MoveGen::legal(&chessboard);
@@ -523,6 +1179,137 @@ impl<'a> MoveGen<'a> {
 
         total_nodes
     }
+
+    /// Runs a suite of perfts, like the ones in the standard perft EPD files.
+    ///
+    /// Each entry pairs a fen with the expected node counts at some depths. One result is
+    /// returned per entry, holding the first [`PerftMismatch`] of the entry, if any, or
+    /// [`PerftSuiteError::InvalidFen`] if its fen could not be loaded.
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::{MoveGen, PerftSuiteError};
+    ///
+    /// let results = MoveGen::perft_suite(&[
+    ///     ("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq -", &[(1, 20), (2, 400), (3, 8902)]),
+    ///     ("8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - -", &[(1, 14), (2, 191)]),
+    ///     ("8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - -", &[(2, 190)]),
+    /// ]);
+    /// assert!(results[0].is_ok());
+    /// assert!(results[1].is_ok());
+    ///
+    /// let Err(PerftSuiteError::Mismatch(mismatch)) = &results[2] else {
+    ///     panic!("expected a mismatch");
+    /// };
+    /// assert_eq!((mismatch.depth, mismatch.expected, mismatch.actual), (2, 190, 191));
+    /// ```
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn perft_suite(entries: &[(&str, &[(u8, u64)])]) -> Vec<Result<(), PerftSuiteError>> {
+        entries
+            .iter()
+            .enumerate()
+            .map(|(index, (fen, depths))| {
+                let chessboard = ChessBoard::from_fen(fen)
+                    .map_err(|error| PerftSuiteError::InvalidFen { index, error })?;
+
+                for &(depth, expected) in depths.iter() {
+                    let actual = Self::perft_nodes(&chessboard, depth);
+                    if actual != expected {
+                        let divide = match depth {
+                            0 => vec![],
+                            _ => MoveGen::legal(&chessboard)
+                                .map(|mv| {
+                                    (mv, Self::perft_nodes(&chessboard.get_child(mv), depth - 1))
+                                })
+                                .collect(),
+                        };
+                        return Err(PerftMismatch {
+                            fen: fen.to_string(),
+                            depth,
+                            expected,
+                            actual,
+                            divide,
+                        }
+                        .into());
+                    }
+                }
+                Ok(())
+            })
+            .collect()
+    }
+
+    /// Counts the leaf nodes of a perft without overflowing on deep searches.
+    #[cfg(feature = "std")]
+    fn perft_nodes(chessboard: &ChessBoard, depth: u8) -> u64 {
+        match depth {
+            0 => 1,
+            1 => Self::count_legal_moves(chessboard) as u64,
+            _ => MoveGen::legal(chessboard)
+                .map(|mv| Self::perft_nodes(&chessboard.get_child(mv), depth - 1))
+                .sum(),
+        }
+    }
+
+    /// Runs a perft on a given [`ChessBoard`], categorizing the leaf nodes.
+    ///
+    /// The categories match the ones published alongside standard perft results, which makes
+    /// this useful for finding move generation bugs.
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::{ChessBoard, MoveGen};
+    ///
+    /// // Create a new chess board.
+    /// let board = ChessBoard::new();
+    ///
+    /// // Run a detailed perft to depth 4.
+    /// let stats = MoveGen::perft_detailed(board, 4);
+    /// assert_eq!(stats.nodes, 197_281);
+    /// assert_eq!(stats.captures, 1_576);
+    /// assert_eq!(stats.checks, 469);
+    /// assert_eq!(stats.checkmates, 8);
+    /// ```
+    #[inline]
+    pub fn perft_detailed(chessboard: ChessBoard, depth: u8) -> PerftStats {
+        let mut stats = PerftStats::default();
+        if depth == 0 {
+            stats.nodes = 1;
+            return stats;
+        }
+
+        for mv in MoveGen::legal(&chessboard) {
+            let mut child_board = chessboard.clone();
+            child_board.make_move(mv);
+
+            if depth > 1 {
+                stats += Self::perft_detailed(child_board, depth - 1);
+                continue;
+            }
+
+            stats.nodes += 1;
+            match mv {
+                Move::Capture { .. } | Move::PromoteCapture { .. } | Move::EnPassant { .. } => {
+                    stats.captures += 1
+                }
+                _ => (),
+            }
+            match mv {
+                Move::EnPassant { .. } => stats.en_passants += 1,
+                Move::Castle { .. } => stats.castles += 1,
+                Move::Promote { .. } | Move::PromoteCapture { .. } => stats.promotions += 1,
+                _ => (),
+            }
+            if !child_board.checkers().is_empty() {
+                stats.checks += 1;
+                if MoveGen::legal(&child_board).is_empty() {
+                    stats.checkmates += 1;
+                }
+            }
+        }
+
+        stats
+    }
 }
 
 /// The [`MoveGen`] struct can iterate through all generated moves.
@@ -546,13 +1333,13 @@ impl Iterator for MoveGen<'_> {
     /// ```
     fn next(&mut self) -> Option<Self::Item> {
         // Make sure there are moves to generate.
-        if self.moves.is_empty() {
+        if self.moves.is_empty() && !self.load_quiets() {
             return None;
         }
 
         if self.moves.back().unwrap().targets.is_empty() {
             self.moves.pop();
-            if self.moves.is_empty() {
+            if self.moves.is_empty() && !self.load_quiets() {
                 return None;
             }
         }
@@ -602,7 +1389,11 @@ impl Iterator for MoveGen<'_> {
 
         // Handle promotion sequence.
         if let Move::Promote { .. } | Move::PromoteCapture { .. } = &mv {
-            self.promote_status = Some(PromoteStatus::PromoteBishop);
+            self.promote_status = Some(if self.underpromotions {
+                PromoteStatus::PromoteBishop
+            } else {
+                PromoteStatus::PromoteQueen
+            });
         } else {
             // Remove the end square from targets.
             self.moves.back_mut().unwrap().targets ^= end.bitboard();
@@ -1,11 +1,15 @@
 use crate::defs::{BitBoard, Square};
-use crate::{ChessBoard, Color, PieceType, Rank};
-use std::mem::MaybeUninit;
+use crate::{ChessBoard, PieceType, Rank};
+use core::mem::MaybeUninit;
 
 /// The [`PieceMoves`] struct stores the location of and the squares a piece targets.
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PieceMoves {
+    /// The square the piece is on.
     pub location: Square,
+
+    /// The squares the piece can move to.
     pub targets: BitBoard,
 }
 
@@ -14,12 +18,42 @@ impl PieceMoves {
     pub const fn new(location: Square, targets: BitBoard) -> Self {
         Self { location, targets }
     }
+
+    /// Counts the number of moves in the [`PieceMoves`], where each promotion counts as
+    /// `per_promotion` moves.
+    pub(crate) fn count_moves(&self, chessboard: &ChessBoard, per_promotion: u32) -> u32 {
+        let moving = chessboard.piece_at(self.location).unwrap().kind;
+
+        // Pawns have special move cases.
+        if moving == PieceType::Pawn {
+            // The rank pawn promote on.
+            let promote_rank = BitBoard::from_rank(Rank::Eighth.relative_to(chessboard.turn()));
+
+            // The promotion moves.
+            let promotions = self.targets & promote_rank;
+
+            // The non-promotion moves.
+            let normal = self.targets & !promotions;
+
+            // Each promotion move increases the total by one move per promotion target, the rest
+            // increase the total normally.
+            per_promotion * promotions.popcnt() as u32 + normal.popcnt() as u32
+        } else {
+            self.targets.popcnt() as u32
+        }
+    }
 }
 
+/// The maximum number of [`PieceMoves`] a [`MoveList`] can store.
+///
+/// The generator pushes at most one [`PieceMoves`] per piece of the side to move, and
+/// [`ChessBoard::from_builder`] refuses boards with more than 18 pieces of one color.
+const MAX_PIECE_MOVES: usize = 18;
+
 /// The [`MoveList`] struct stores a list of moves.
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct MoveList {
-    data: MaybeUninit<[PieceMoves; 18]>,
+    data: MaybeUninit<[PieceMoves; MAX_PIECE_MOVES]>,
     length: usize,
 }
 
@@ -40,6 +74,7 @@ impl MoveList {
     /// Adds a new item to the [`MoveList`] if it contains moves.
     pub fn push(&mut self, piece_moves: PieceMoves) {
         if !piece_moves.targets.is_empty() {
+            debug_assert!(self.length < MAX_PIECE_MOVES, "the move list is full");
             unsafe {
                 *self.data.assume_init_mut().get_unchecked_mut(self.length) = piece_moves;
             }
@@ -82,40 +117,27 @@ impl MoveList {
         }
     }
 
-    /// Counts the total number of moves in the [`MoveList`].
-    pub fn count_moves(&self, chessboard: &ChessBoard) -> u32 {
+    /// Copies the stored [`PieceMoves`] into a [`Vec`].
+    #[cfg(feature = "std")]
+    pub fn to_vec(&self) -> Vec<PieceMoves> {
+        unsafe {
+            self.data
+                .assume_init_ref()
+                .get_unchecked(..self.length)
+                .to_vec()
+        }
+    }
+
+    /// Counts the total number of moves in the [`MoveList`], where each promotion counts as
+    /// `per_promotion` moves.
+    pub fn count_moves(&self, chessboard: &ChessBoard, per_promotion: u32) -> u32 {
         // The total number of moves.
         let mut total = 0;
 
         // Count each move.
         for i in 0..self.length {
             let piece_moves = unsafe { self.data.assume_init_ref().get_unchecked(i) };
-            let piece_sq = piece_moves.location;
-            let moving = chessboard.piece_at(piece_sq).unwrap().kind;
-
-            // Pawns have special move cases.
-            if moving == PieceType::Pawn {
-                // The rank pawn promote on.
-                let promote_rank = match chessboard.turn() {
-                    Color::White => BitBoard::from_rank(Rank::Eighth),
-                    Color::Black => BitBoard::from_rank(Rank::First),
-                };
-
-                // The promotion moves.
-                let promotions = piece_moves.targets & promote_rank;
-
-                // The non-promotion moves.
-                let normal = piece_moves.targets & !promotions;
-
-                // Each promotion move increases the total 4x.
-                total += 4 * promotions.popcnt() as u32;
-
-                // The rest of the moves increase the total normally.
-                total += normal.popcnt() as u32;
-            } else {
-                // Increase the total numer of moves.
-                total += piece_moves.targets.popcnt() as u32;
-            }
+            total += piece_moves.count_moves(chessboard, per_promotion);
         }
 
         total
@@ -0,0 +1,100 @@
+#[cfg(feature = "std")]
+use crate::chessboard::{FenLoadError, Move};
+use core::ops::{Add, AddAssign};
+#[cfg(feature = "std")]
+use thiserror::Error;
+
+/// The [`PerftStats`] struct holds a breakdown of the leaf nodes of a perft.
+///
+/// It is produced by [`MoveGen::perft_detailed`](crate::MoveGen::perft_detailed).
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PerftStats {
+    /// The number of leaf nodes.
+    pub nodes: u64,
+
+    /// The number of moves into a leaf node that capture a piece, including en passant.
+    pub captures: u64,
+
+    /// The number of en passant moves into a leaf node.
+    pub en_passants: u64,
+
+    /// The number of castling moves into a leaf node.
+    pub castles: u64,
+
+    /// The number of promotion moves into a leaf node.
+    pub promotions: u64,
+
+    /// The number of leaf nodes where the side to move is in check.
+    pub checks: u64,
+
+    /// The number of leaf nodes where the side to move is checkmated.
+    pub checkmates: u64,
+}
+
+impl Add for PerftStats {
+    type Output = Self;
+
+    fn add(mut self, rhs: Self) -> Self::Output {
+        self += rhs;
+        self
+    }
+}
+
+impl AddAssign for PerftStats {
+    fn add_assign(&mut self, rhs: Self) {
+        self.nodes += rhs.nodes;
+        self.captures += rhs.captures;
+        self.en_passants += rhs.en_passants;
+        self.castles += rhs.castles;
+        self.promotions += rhs.promotions;
+        self.checks += rhs.checks;
+        self.checkmates += rhs.checkmates;
+    }
+}
+
+/// The [`PerftMismatch`] struct describes a perft result that did not match the expected count.
+///
+/// It is produced by [`MoveGen::perft_suite`](crate::MoveGen::perft_suite). The node count of
+/// each root move is included, so it can be compared with the output of another engine to find
+/// the move that is generated incorrectly.
+#[cfg(feature = "std")]
+#[derive(Error, Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[error("perft of '{fen}' to depth {depth} found {actual} nodes, expected {expected}")]
+pub struct PerftMismatch {
+    /// The fen of the position.
+    pub fen: String,
+
+    /// The depth of the perft.
+    pub depth: u8,
+
+    /// The expected number of leaf nodes.
+    pub expected: u64,
+
+    /// The number of leaf nodes that were found.
+    pub actual: u64,
+
+    /// The number of leaf nodes found after each legal move of the position.
+    pub divide: Vec<(Move, u64)>,
+}
+
+/// The [`PerftSuiteError`] enum is the error type for an entry of
+/// [`MoveGen::perft_suite`](crate::MoveGen::perft_suite).
+#[cfg(feature = "std")]
+#[derive(Error, Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PerftSuiteError {
+    #[error("entry {} has a fen that could not be loaded: {error}", .index + 1)]
+    InvalidFen {
+        /// The index of the entry in the suite.
+        index: usize,
+
+        /// The reason the fen could not be loaded.
+        #[source]
+        error: FenLoadError,
+    },
+
+    #[error(transparent)]
+    Mismatch(#[from] PerftMismatch),
+}
@@ -0,0 +1,54 @@
+use crate::defs::PieceType;
+
+/// The [`PieceValues`] struct holds the material value of each [`PieceType`].
+///
+/// It is used by [`ChessBoard::material_with`](crate::ChessBoard::material_with), so evaluations
+/// and tuning tools can pick their own values. The king has no material value.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PieceValues {
+    pub pawn: i32,
+    pub knight: i32,
+    pub bishop: i32,
+    pub rook: i32,
+    pub queen: i32,
+}
+
+impl PieceValues {
+    /// The classical centipawn values: 100, 320, 330, 500 and 900.
+    pub const DEFAULT: PieceValues = PieceValues {
+        pawn: 100,
+        knight: 320,
+        bishop: 330,
+        rook: 500,
+        queen: 900,
+    };
+
+    /// Gets the value of a [`PieceType`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::{PieceType, PieceValues};
+    ///
+    /// assert_eq!(PieceValues::DEFAULT.value(PieceType::Rook), 500);
+    /// assert_eq!(PieceValues::DEFAULT.value(PieceType::King), 0);
+    /// ```
+    #[inline]
+    pub const fn value(&self, kind: PieceType) -> i32 {
+        match kind {
+            PieceType::Pawn => self.pawn,
+            PieceType::Knight => self.knight,
+            PieceType::Bishop => self.bishop,
+            PieceType::Rook => self.rook,
+            PieceType::Queen => self.queen,
+            PieceType::King => 0,
+        }
+    }
+}
+
+impl Default for PieceValues {
+    #[inline]
+    fn default() -> Self {
+        PieceValues::DEFAULT
+    }
+}
@@ -5,6 +5,45 @@ use crate::mask_gen::sliders::{get_bishop_attacks_slow, get_rook_attacks_slow};
 
 include!(concat!(env!("OUT_DIR"), "/generated_tables.rs"));
 
+impl BitBoard {
+    /// Gets a [`BitBoard`] of the squares between two [`Square`]'s.
+    ///
+    /// The squares must share a rank, file, or diagonal, otherwise an empty [`BitBoard`] is
+    /// returned. Neither of the given squares is included in the result.
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::{BitBoard, Square};
+    ///
+    /// assert_eq!(BitBoard::between(Square::A1, Square::D4), BitBoard::from_squares(&[Square::B2, Square::C3]));
+    /// assert_eq!(BitBoard::between(Square::E1, Square::E3), BitBoard::from_square(Square::E2));
+    /// assert_eq!(BitBoard::between(Square::E1, Square::E2), BitBoard::EMPTY);
+    /// assert_eq!(BitBoard::between(Square::A1, Square::B3), BitBoard::EMPTY);
+    /// ```
+    #[inline]
+    pub fn between(a: Square, b: Square) -> BitBoard {
+        get_direct_connection(a, b)
+    }
+
+    /// Gets a [`BitBoard`] of the squares from a [`Square`] to the edge of the board in a given
+    /// [`Direction`].
+    ///
+    /// The starting square is not included in the result.
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::{BitBoard, Direction, Square};
+    ///
+    /// assert_eq!(BitBoard::ray(Square::E6, Direction::Up), BitBoard::from_squares(&[Square::E7, Square::E8]));
+    /// assert_eq!(BitBoard::ray(Square::C3, Direction::DownLeft), BitBoard::from_squares(&[Square::B2, Square::A1]));
+    /// assert_eq!(BitBoard::ray(Square::H4, Direction::Right), BitBoard::EMPTY);
+    /// ```
+    #[inline]
+    pub fn ray(from: Square, dir: Direction) -> BitBoard {
+        RAYS[from.index()][dir.index()]
+    }
+}
+
 /// Returns a [`BitBoard`] mask containing the squares under attack by a pawn from a given square
 pub fn get_pawn_attacks(square: Square, color: Color) -> BitBoard {
     PAWN_ATTACKS[color.index()][square.index()]
@@ -0,0 +1,156 @@
+use crate::chessboard::Move;
+use crate::defs::Square;
+
+/// The [`Transform`] enum represents a symmetry of the chess board.
+///
+/// It is produced by [`ChessBoard::canonicalize`](crate::ChessBoard::canonicalize), and can map
+/// squares and moves between a position and its transformed version.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Transform {
+    /// Leaves every square where it is.
+    Identity,
+
+    /// Swaps the a-file with the h-file, the b-file with the g-file, and so on.
+    MirrorFiles,
+
+    /// Swaps the first rank with the eighth rank, the second rank with the seventh, and so on.
+    FlipRanks,
+
+    /// Turns the board half way around, so a1 goes to h8.
+    Rotate180,
+
+    /// Flips the board along the a1-h8 diagonal, so a2 goes to b1.
+    FlipDiagonal,
+
+    /// Flips the board along the a8-h1 diagonal, so a1 goes to h8 and a2 goes to g8.
+    FlipAntiDiagonal,
+
+    /// Turns the board a quarter clockwise, so a1 goes to a8.
+    Rotate90,
+
+    /// Turns the board a quarter counterclockwise, so a1 goes to h1.
+    Rotate270,
+}
+
+impl Transform {
+    /// All the [`Transform`]'s, starting with [`Transform::Identity`].
+    pub const ALL: [Transform; 8] = [
+        Transform::Identity,
+        Transform::MirrorFiles,
+        Transform::FlipRanks,
+        Transform::Rotate180,
+        Transform::FlipDiagonal,
+        Transform::FlipAntiDiagonal,
+        Transform::Rotate90,
+        Transform::Rotate270,
+    ];
+
+    /// Gets the [`Transform`] that undoes this one.
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::{Transform, SQUARES};
+    ///
+    /// for transform in Transform::ALL {
+    ///     for square in SQUARES {
+    ///         assert_eq!(transform.inverse().square(transform.square(square)), square);
+    ///     }
+    /// }
+    /// ```
+    #[inline]
+    pub const fn inverse(self) -> Self {
+        match self {
+            Transform::Rotate90 => Transform::Rotate270,
+            Transform::Rotate270 => Transform::Rotate90,
+            transform => transform,
+        }
+    }
+
+    /// Gets the [`Square`] a given [`Square`] goes to under the [`Transform`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::{Square, Transform};
+    ///
+    /// assert_eq!(Transform::MirrorFiles.square(Square::B3), Square::G3);
+    /// assert_eq!(Transform::FlipRanks.square(Square::B3), Square::B6);
+    /// assert_eq!(Transform::FlipDiagonal.square(Square::B3), Square::C2);
+    /// assert_eq!(Transform::Rotate90.square(Square::B3), Square::C7);
+    /// ```
+    #[inline]
+    pub fn square(self, square: Square) -> Square {
+        let file = square.as_u8() & 7;
+        let rank = square.as_u8() >> 3;
+        let (file, rank) = match self {
+            Transform::Identity => (file, rank),
+            Transform::MirrorFiles => (7 - file, rank),
+            Transform::FlipRanks => (file, 7 - rank),
+            Transform::Rotate180 => (7 - file, 7 - rank),
+            Transform::FlipDiagonal => (rank, file),
+            Transform::FlipAntiDiagonal => (7 - rank, 7 - file),
+            Transform::Rotate90 => (rank, 7 - file),
+            Transform::Rotate270 => (7 - rank, file),
+        };
+        Square::from_u8(rank * 8 + file).unwrap()
+    }
+
+    /// Gets the [`Move`] a given [`Move`] becomes under the [`Transform`].
+    ///
+    /// Only the start and end squares are transformed. Castling moves are never transformed by
+    /// [`ChessBoard::canonicalize`](crate::ChessBoard::canonicalize), since positions with
+    /// castling rights are always left as they are.
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::{ChessBoard, MoveGen};
+    ///
+    /// let board = ChessBoard::from_fen("8/8/8/8/8/2k5/8/K6Q w - -").unwrap();
+    /// let (canonical, transform) = board.canonicalize();
+    ///
+    /// // Map a move on the canonical board back to the original board.
+    /// for mv in MoveGen::legal(&canonical) {
+    ///     let original = transform.inverse().apply_move(mv);
+    ///     assert!(MoveGen::legal(&board).any(|legal| legal == original));
+    /// }
+    /// ```
+    #[inline]
+    pub fn apply_move(self, mv: Move) -> Move {
+        let sq = |square| self.square(square);
+        match mv {
+            Move::Quiet { start, end, moving } => Move::Quiet {
+                start: sq(start),
+                end: sq(end),
+                moving,
+            },
+            Move::Capture { start, end, moving } => Move::Capture {
+                start: sq(start),
+                end: sq(end),
+                moving,
+            },
+            Move::Castle { start, end, side } => Move::Castle {
+                start: sq(start),
+                end: sq(end),
+                side,
+            },
+            Move::DoublePawnPush { start, end } => Move::DoublePawnPush {
+                start: sq(start),
+                end: sq(end),
+            },
+            Move::EnPassant { start, end } => Move::EnPassant {
+                start: sq(start),
+                end: sq(end),
+            },
+            Move::Promote { start, end, target } => Move::Promote {
+                start: sq(start),
+                end: sq(end),
+                target,
+            },
+            Move::PromoteCapture { start, end, target } => Move::PromoteCapture {
+                start: sq(start),
+                end: sq(end),
+                target,
+            },
+        }
+    }
+}
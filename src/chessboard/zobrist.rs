@@ -1,10 +1,19 @@
 use super::ChessBoard;
 use crate::defs::*;
+use crate::zobrist_gen::{
+    generate_castle_right_zobrist, generate_en_passant_zobrist, generate_halfmove_zobrist,
+    generate_piece_zobrist, generate_turn_zobrist,
+};
 
 include!(concat!(env!("OUT_DIR"), "/zobrist.rs"));
 
-#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
 /// The [`ZobristHash`] is the hash of a [`ChessBoard`].
+///
+/// The random numbers behind the hash are generated at build time from fixed seeds, so a given
+/// position always hashes to the same value, regardless of the build or enabled features. This
+/// makes hashes safe to persist (e.g. in opening books). Any change to the hash values is treated
+/// as a breaking change.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ZobristHash(u64);
 
@@ -16,6 +25,14 @@ impl ZobristHash {
     }
 
     /// Converts the [`ZobristHash`] into a [`u64`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::ChessBoard;
+    ///
+    /// // Hash values are stable across builds.
+    /// assert_eq!(ChessBoard::new().hash().to_u64(), 0x6FAEE8FA1013FD66);
+    /// ```
     #[inline]
     pub const fn to_u64(self) -> u64 {
         self.0
@@ -28,6 +45,15 @@ impl ZobristHash {
         self.0 ^= PIECE_ZOBRIST[piece.color.index()][piece.kind.index()][square.index()];
     }
 
+    /// Adds/removes the nth piece of a kind from the [`ZobristHash`].
+    ///
+    /// This is used for material hashes, where only piece counts matter.
+    #[inline]
+    pub(super) fn material(&mut self, piece: impl Into<Piece>, nth: u8) {
+        let piece = piece.into();
+        self.0 ^= PIECE_ZOBRIST[piece.color.index()][piece.kind.index()][nth as usize];
+    }
+
     /// Adds/removes a castle right from the [`ZobristHash`].
     #[inline]
     pub(super) fn castle_right(&mut self, side: CastleSide, color: Color) {
@@ -43,9 +69,140 @@ impl ZobristHash {
         self.0 ^= EN_PASSANT_ZOBRIST[square.file() as usize];
     }
 
+    /// Adds/removes a halfmove clock value from the [`ZobristHash`].
+    #[inline]
+    pub(super) fn halfmoves(&mut self, halfmoves: u8) {
+        self.0 ^= HALFMOVE_ZOBRIST[halfmoves as usize];
+    }
+
     /// Toggles the turn in the [`ZobristHash`].
     #[inline]
     pub(super) fn toggle_turn(&mut self) {
         self.0 ^= TURN_ZOBRIST;
     }
 }
+
+impl From<ZobristHash> for u64 {
+    /// Converts the [`ZobristHash`] into a [`u64`], like [`ZobristHash::to_u64`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::ChessBoard;
+    ///
+    /// let key: u64 = ChessBoard::new().hash().into();
+    /// assert_eq!(key, 0x6FAEE8FA1013FD66);
+    /// ```
+    #[inline]
+    fn from(hash: ZobristHash) -> Self {
+        hash.to_u64()
+    }
+}
+
+/// The [`DynamicZobrist`] struct hashes [`ChessBoard`]'s with random numbers generated at runtime.
+///
+/// The built-in [`ZobristHash`] uses random numbers generated from fixed seeds at build time.
+/// A [`DynamicZobrist`] generates its random numbers from a chosen seed instead, which is useful
+/// for experimenting with hash collisions. It is much slower than [`ChessBoard::hash`], as the
+/// hash is computed from scratch.
+///
+/// # Examples
+/// ```
+/// use rchess::{ChessBoard, DynamicZobrist};
+///
+/// let board = ChessBoard::new();
+///
+/// // The default random numbers are the built-in ones.
+/// assert_eq!(DynamicZobrist::default().hash(&board), board.hash().to_u64());
+///
+/// // Other seeds give other hashes.
+/// let zobrist = DynamicZobrist::new(42);
+/// assert_ne!(zobrist.hash(&board), board.hash().to_u64());
+/// assert_eq!(zobrist.hash(&board), DynamicZobrist::new(42).hash(&board));
+/// ```
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct DynamicZobrist {
+    pieces: [[[u64; 64]; 6]; 2],
+    castle_rights: [[u64; 2]; 2],
+    en_passant: [u64; 8],
+    halfmoves: [u64; 256],
+    turn: u64,
+}
+
+impl DynamicZobrist {
+    /// Creates a new [`DynamicZobrist`] with random numbers generated from the given seed.
+    #[inline]
+    pub fn new(seed: u64) -> Self {
+        // Give every table its own seed, like the built-in random numbers.
+        let mut rng = fastrand::Rng::with_seed(seed);
+        let mut next_seed = || rng.u64(0..=u64::MAX);
+
+        Self {
+            pieces: generate_piece_zobrist(next_seed()),
+            castle_rights: generate_castle_right_zobrist(next_seed()),
+            en_passant: generate_en_passant_zobrist(next_seed()),
+            halfmoves: generate_halfmove_zobrist(next_seed()),
+            turn: generate_turn_zobrist(next_seed()),
+        }
+    }
+
+    /// Hashes a [`ChessBoard`] in the same way as [`ChessBoard::hash`].
+    #[inline]
+    pub fn hash(&self, chessboard: &ChessBoard) -> u64 {
+        let mut hash = 0;
+
+        for color in [Color::White, Color::Black] {
+            for kind in [
+                PieceType::Pawn,
+                PieceType::Knight,
+                PieceType::Bishop,
+                PieceType::Rook,
+                PieceType::Queen,
+                PieceType::King,
+            ] {
+                for square in chessboard.query((kind, color)) {
+                    hash ^= self.pieces[color.index()][kind.index()][square.index()];
+                }
+            }
+
+            if chessboard.is_castle_right_set(CastleSide::Kingside, color) {
+                hash ^= self.castle_rights[0][color.index()];
+            }
+            if chessboard.is_castle_right_set(CastleSide::Queenside, color) {
+                hash ^= self.castle_rights[1][color.index()];
+            }
+        }
+
+        // The en passant file is only hashed when it can be captured.
+        if let Some(square) = chessboard.en_passant_sq() {
+            if chessboard.ep_is_capturable() {
+                hash ^= self.en_passant[square.file() as usize];
+            }
+        }
+
+        if chessboard.turn() == Color::Black {
+            hash ^= self.turn;
+        }
+
+        hash
+    }
+
+    /// Hashes a [`ChessBoard`] including its half move clock, in the same way as
+    /// [`ChessBoard::full_key`].
+    #[inline]
+    pub fn full_key(&self, chessboard: &ChessBoard) -> u64 {
+        self.hash(chessboard) ^ self.halfmoves[chessboard.halfmoves() as usize]
+    }
+}
+
+impl Default for DynamicZobrist {
+    /// The default [`DynamicZobrist`] uses the built-in random numbers of [`ZobristHash`].
+    fn default() -> Self {
+        Self {
+            pieces: PIECE_ZOBRIST,
+            castle_rights: CASTLE_RIGHTS_ZOBRIST,
+            en_passant: EN_PASSANT_ZOBRIST,
+            halfmoves: HALFMOVE_ZOBRIST,
+            turn: TURN_ZOBRIST,
+        }
+    }
+}
@@ -1,6 +1,6 @@
 use super::{Direction, File, Rank, Square, FILES, RANKS};
-use std::fmt::{Display, Formatter};
-use std::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not};
+use core::fmt::{Display, Formatter};
+use core::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not};
 
 /// The [`BitBoard`] struct stores a series of squares on a chess board.
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
@@ -228,6 +228,97 @@ impl BitBoard {
         self.val == 0
     }
 
+    /// Checks if the [`BitBoard`] contains exactly one square.
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::{BitBoard, Square};
+    ///
+    /// assert!(BitBoard::from_square(Square::A1).is_single());
+    /// assert!(!BitBoard::from_squares(&[Square::A1, Square::H8]).is_single());
+    /// assert!(!BitBoard::EMPTY.is_single());
+    /// ```
+    #[inline]
+    pub const fn is_single(&self) -> bool {
+        self.val != 0 && self.val & (self.val - 1) == 0
+    }
+
+    /// Gets the only [`Square`] in the [`BitBoard`].
+    ///
+    /// Returns a `None` value if the [`BitBoard`] is empty or contains more than one square.
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::{BitBoard, Square};
+    ///
+    /// assert_eq!(BitBoard::from_square(Square::E4).single(), Some(Square::E4));
+    /// assert_eq!(BitBoard::from_squares(&[Square::A1, Square::H8]).single(), None);
+    /// assert_eq!(BitBoard::EMPTY.single(), None);
+    /// ```
+    #[inline]
+    pub const fn single(&self) -> Option<Square> {
+        if !self.is_single() {
+            return None;
+        }
+        self.b_scan_forward()
+    }
+
+    /// Checks if the [`BitBoard`] contains at least two squares.
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::{BitBoard, Square};
+    ///
+    /// assert!(BitBoard::from_squares(&[Square::A1, Square::H8]).more_than_one());
+    /// assert!(!BitBoard::from_square(Square::A1).more_than_one());
+    /// assert!(!BitBoard::EMPTY.more_than_one());
+    /// ```
+    #[inline]
+    pub const fn more_than_one(&self) -> bool {
+        self.val & self.val.wrapping_sub(1) != 0
+    }
+
+    /// Gets a [`BitBoard`] of only the lowest-indexed square of the [`BitBoard`].
+    ///
+    /// Returns an empty [`BitBoard`] if the [`BitBoard`] is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::{BitBoard, Square};
+    ///
+    /// let bitboard = BitBoard::from_squares(&[Square::C2, Square::E5, Square::G7]);
+    /// assert_eq!(bitboard.lsb(), Square::C2.bitboard());
+    /// assert_eq!(BitBoard::EMPTY.lsb(), BitBoard::EMPTY);
+    /// ```
+    #[inline]
+    pub const fn lsb(&self) -> BitBoard {
+        BitBoard {
+            val: self.val & self.val.wrapping_neg(),
+        }
+    }
+
+    /// Gets a [`BitBoard`] of only the highest-indexed square of the [`BitBoard`].
+    ///
+    /// Returns an empty [`BitBoard`] if the [`BitBoard`] is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::{BitBoard, Square};
+    ///
+    /// let bitboard = BitBoard::from_squares(&[Square::C2, Square::E5, Square::G7]);
+    /// assert_eq!(bitboard.msb(), Square::G7.bitboard());
+    /// assert_eq!(BitBoard::EMPTY.msb(), BitBoard::EMPTY);
+    /// ```
+    #[inline]
+    pub const fn msb(&self) -> BitBoard {
+        if self.val == 0 {
+            return BitBoard::EMPTY;
+        }
+        BitBoard {
+            val: 1 << (63 - self.val.leading_zeros()),
+        }
+    }
+
     /// Creates a new [`BitBoard`] from a [`u64`].
     ///
     /// # Examples
@@ -318,6 +409,101 @@ impl BitBoard {
         self
     }
 
+    /// Fills every [`Square`] of the [`BitBoard`] up to the eighth rank.
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::{BitBoard, Square};
+    ///
+    /// let bb = BitBoard::from_square(Square::E6);
+    /// assert_eq!(bb.north_fill(), BitBoard::from_squares(&[Square::E6, Square::E7, Square::E8]));
+    /// ```
+    #[inline]
+    pub const fn north_fill(mut self) -> Self {
+        self.val |= self.val << 8;
+        self.val |= self.val << 16;
+        self.val |= self.val << 32;
+        self
+    }
+
+    /// Fills every [`Square`] of the [`BitBoard`] down to the first rank.
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::{BitBoard, Square};
+    ///
+    /// let bb = BitBoard::from_square(Square::E3);
+    /// assert_eq!(bb.south_fill(), BitBoard::from_squares(&[Square::E1, Square::E2, Square::E3]));
+    /// ```
+    #[inline]
+    pub const fn south_fill(mut self) -> Self {
+        self.val |= self.val >> 8;
+        self.val |= self.val >> 16;
+        self.val |= self.val >> 32;
+        self
+    }
+
+    /// Fills the whole [`File`] of every [`Square`] in the [`BitBoard`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::{BitBoard, File, Square};
+    ///
+    /// let bb = BitBoard::from_square(Square::E5);
+    /// assert_eq!(bb.file_fill(), BitBoard::from_file(File::E));
+    /// ```
+    #[inline]
+    pub const fn file_fill(self) -> Self {
+        self.north_fill().or(self.south_fill())
+    }
+
+    /// Fills every [`Square`] of the [`BitBoard`] towards the eighth rank, stopping before any
+    /// [`Square`] in `blockers`.
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::{BitBoard, Square};
+    ///
+    /// let bb = BitBoard::from_square(Square::E4);
+    /// let blockers = BitBoard::from_square(Square::E7);
+    /// assert_eq!(
+    ///     bb.north_occluded_fill(blockers),
+    ///     BitBoard::from_squares(&[Square::E4, Square::E5, Square::E6])
+    /// );
+    /// ```
+    #[inline]
+    pub const fn north_occluded_fill(mut self, blockers: Self) -> Self {
+        let mut empty = !blockers.val;
+        self.val |= empty & (self.val << 8);
+        empty &= empty << 8;
+        self.val |= empty & (self.val << 16);
+        empty &= empty << 16;
+        self.val |= empty & (self.val << 32);
+        self
+    }
+
+    /// Fills every [`Square`] of the [`BitBoard`] towards the first rank, stopping before any
+    /// [`Square`] in `blockers`.
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::{BitBoard, Square};
+    ///
+    /// let bb = BitBoard::from_square(Square::E4);
+    /// let blockers = BitBoard::from_square(Square::E2);
+    /// assert_eq!(bb.south_occluded_fill(blockers), BitBoard::from_squares(&[Square::E3, Square::E4]));
+    /// ```
+    #[inline]
+    pub const fn south_occluded_fill(mut self, blockers: Self) -> Self {
+        let mut empty = !blockers.val;
+        self.val |= empty & (self.val >> 8);
+        empty &= empty >> 8;
+        self.val |= empty & (self.val >> 16);
+        empty &= empty >> 16;
+        self.val |= empty & (self.val >> 32);
+        self
+    }
+
     /// Moves all the [`Square`]'s in the [`BitBoard`] in a given [`Direction`].
     ///
     /// # Examples
@@ -410,6 +596,75 @@ impl BitBoard {
         self.val = !self.val;
         self
     }
+
+    /// Iterates through every subset of the [`BitBoard`], including the empty and full subsets.
+    ///
+    /// The subsets are enumerated with the carry-rippler trick, starting from
+    /// [`BitBoard::EMPTY`] and ending with the [`BitBoard`] itself.
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::{BitBoard, Square};
+    ///
+    /// let bitboard = BitBoard::from_squares(&[Square::A1, Square::C3]);
+    /// let subsets = bitboard.subsets().collect::<Vec<_>>();
+    /// assert_eq!(
+    ///     subsets,
+    ///     vec![
+    ///         BitBoard::EMPTY,
+    ///         Square::A1.bitboard(),
+    ///         Square::C3.bitboard(),
+    ///         bitboard,
+    ///     ]
+    /// );
+    /// ```
+    #[inline]
+    pub fn subsets(self) -> impl Iterator<Item = BitBoard> {
+        let mask = self.val;
+        let mut subset = Some(0u64);
+        core::iter::from_fn(move || {
+            let current = subset?;
+
+            // Add one to the masked bits, letting the carry ripple through the unmasked bits.
+            let next = current.wrapping_sub(mask) & mask;
+            subset = if next == 0 { None } else { Some(next) };
+
+            Some(BitBoard { val: current })
+        })
+    }
+
+    /// Formats the [`BitBoard`] with a label, its value in hex, and file and rank coordinates.
+    ///
+    /// This is meant for debugging, the [`Display`] implementation gives a more compact grid.
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::{BitBoard, Rank};
+    ///
+    /// let rank_2 = BitBoard::from_rank(Rank::Second);
+    /// let expected = "\
+    /// rank 2 (0x000000000000FF00)
+    ///   A B C D E F G H
+    /// 8 - - - - - - - -
+    /// 7 - - - - - - - -
+    /// 6 - - - - - - - -
+    /// 5 - - - - - - - -
+    /// 4 - - - - - - - -
+    /// 3 - - - - - - - -
+    /// 2 @ @ @ @ @ @ @ @
+    /// 1 - - - - - - - -
+    /// ";
+    /// assert_eq!(rank_2.debug_string("rank 2"), expected);
+    /// ```
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn debug_string(&self, label: &str) -> String {
+        let mut string = format!("{} (0x{:016X})\n  A B C D E F G H\n", label, self.val);
+        for (rank, row) in RANKS.into_iter().rev().zip(self.to_string().lines()) {
+            string += &format!("{} {}\n", rank.to_u8() + 1, row);
+        }
+        string
+    }
 }
 
 impl BitOr for BitBoard {
@@ -499,7 +754,7 @@ impl Iterator for BitBoard {
 impl Display for BitBoard {
     /// Displays the [`BitBoard`] in a readable manner.
     #[inline]
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         for rank in RANKS.into_iter().rev() {
             for file in FILES {
                 let square = Square::at(rank, file);
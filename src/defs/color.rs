@@ -1,4 +1,4 @@
-use std::ops::Not;
+use core::ops::Not;
 
 /// The [`Color`] enum represents the color of a chess piece.
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
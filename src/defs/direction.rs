@@ -1,4 +1,4 @@
-use super::{BitBoard, File, Rank};
+use super::{BitBoard, File, Rank, Square};
 
 /// All the directions.
 pub const DIRS: [Direction; 8] = [
@@ -41,6 +41,42 @@ impl Direction {
         *self as usize
     }
 
+    /// Gets the [`Direction`] to step in to get from one [`Square`] to another.
+    ///
+    /// Returns `None` if the squares are the same, or do not share a rank, file or diagonal.
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::{Direction, Square};
+    ///
+    /// assert_eq!(Direction::between(Square::E1, Square::E8), Some(Direction::Up));
+    /// assert_eq!(Direction::between(Square::H8, Square::A1), Some(Direction::DownLeft));
+    /// assert_eq!(Direction::between(Square::A1, Square::B3), None);
+    /// ```
+    pub const fn between(from: Square, to: Square) -> Option<Direction> {
+        let file_delta = to.file() as i8 - from.file() as i8;
+        let rank_delta = to.rank() as i8 - from.rank() as i8;
+
+        if file_delta == 0 && rank_delta == 0 {
+            return None;
+        }
+
+        if file_delta != 0 && rank_delta != 0 && file_delta.abs() != rank_delta.abs() {
+            return None;
+        }
+
+        Some(match (file_delta.signum(), rank_delta.signum()) {
+            (0, 1) => Direction::Up,
+            (0, _) => Direction::Down,
+            (-1, 0) => Direction::Left,
+            (_, 0) => Direction::Right,
+            (-1, 1) => Direction::UpLeft,
+            (_, 1) => Direction::UpRight,
+            (-1, _) => Direction::DownLeft,
+            (_, _) => Direction::DownRight,
+        })
+    }
+
     /// Gets a [`BitBoard`] containing the squares of the edge a [`Direction`] will eventually hit.
     ///
     /// # Examples
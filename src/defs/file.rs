@@ -1,4 +1,5 @@
 use super::Square;
+use core::fmt::{Display, Formatter};
 
 /// All the files in order.
 pub const FILES: [File; 8] = [
@@ -75,7 +76,7 @@ impl File {
             return None;
         }
         // SAFETY: The index is in a valid range.
-        unsafe { std::mem::transmute(val) }
+        unsafe { core::mem::transmute(val) }
     }
 
     /// Creates a new [`File`] from an [`u8`].
@@ -96,7 +97,69 @@ impl File {
         match Self::from_u8(val) {
             Some(file) => file,
             // SAFETY: Caller upholds the safety contract.
-            None => unsafe { std::hint::unreachable_unchecked() },
+            None => unsafe { core::hint::unreachable_unchecked() },
         }
     }
+
+    /// Moves the [`File`] left by one.
+    ///
+    /// If the [`File`] is the 'A' file, a `None` value is returned.
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::File;
+    ///
+    /// assert_eq!(File::H.left(), Some(File::G));
+    /// assert_eq!(File::A.left(), None);
+    /// ```
+    #[inline]
+    pub const fn left(self) -> Option<Self> {
+        if self.to_u8() == 0 {
+            return None;
+        }
+        Self::from_u8(self.to_u8() - 1)
+    }
+
+    /// Moves the [`File`] right by one.
+    ///
+    /// If the [`File`] is the 'H' file, a `None` value is returned.
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::File;
+    ///
+    /// assert_eq!(File::A.right(), Some(File::B));
+    /// assert_eq!(File::H.right(), None);
+    /// ```
+    #[inline]
+    pub const fn right(self) -> Option<Self> {
+        Self::from_u8(self.to_u8() + 1)
+    }
+}
+
+impl Display for File {
+    /// Displays the [`File`] as a lowercase letter, like in algebraic chess notation.
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::File;
+    ///
+    /// assert_eq!(&File::A.to_string(), "a");
+    /// assert_eq!(&File::H.to_string(), "h");
+    /// ```
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let c = match self {
+            File::A => 'a',
+            File::B => 'b',
+            File::C => 'c',
+            File::D => 'd',
+            File::E => 'e',
+            File::F => 'f',
+            File::G => 'g',
+            File::H => 'h',
+        };
+
+        write!(f, "{}", c)
+    }
 }
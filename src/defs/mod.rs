@@ -16,6 +16,9 @@ pub use piece::*;
 pub use rank::*;
 pub use square::*;
 
+use core::fmt::{Display, Formatter};
+use core::str::FromStr;
+
 /// The starting chess position's fen.
 pub const START_FEN: &str = "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq -";
 
@@ -26,3 +29,50 @@ pub enum CastleSide {
     Kingside,
     Queenside,
 }
+
+impl Display for CastleSide {
+    /// Displays the [`CastleSide`] in castling notation.
+    ///
+    /// [`CastleSide::Kingside`] is written as `O-O` and [`CastleSide::Queenside`] as `O-O-O`.
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::CastleSide;
+    ///
+    /// assert_eq!(CastleSide::Kingside.to_string(), "O-O");
+    /// assert_eq!(CastleSide::Queenside.to_string(), "O-O-O");
+    /// ```
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            CastleSide::Kingside => write!(f, "O-O"),
+            CastleSide::Queenside => write!(f, "O-O-O"),
+        }
+    }
+}
+
+impl FromStr for CastleSide {
+    type Err = ();
+
+    /// Parses a [`CastleSide`] from castling notation.
+    ///
+    /// `O-O` is [`CastleSide::Kingside`] and `O-O-O` is [`CastleSide::Queenside`]. Zeros are also
+    /// accepted in place of the letters.
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::CastleSide;
+    ///
+    /// assert_eq!("O-O".parse(), Ok(CastleSide::Kingside));
+    /// assert_eq!("0-0-0".parse(), Ok(CastleSide::Queenside));
+    /// assert!("O-O-O-O".parse::<CastleSide>().is_err());
+    /// ```
+    #[inline]
+    fn from_str(str: &str) -> Result<Self, Self::Err> {
+        match str {
+            "O-O" | "0-0" => Ok(CastleSide::Kingside),
+            "O-O-O" | "0-0-0" => Ok(CastleSide::Queenside),
+            _ => Err(()),
+        }
+    }
+}
@@ -1,7 +1,23 @@
 use super::Color;
 
 /// The [`PieceType`] enum represents a type of chess piece.
-#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+///
+/// [`PieceType`]s are ordered by declaration order: pawn, knight, bishop, rook, queen, then king.
+/// This follows material value, except that knights and bishops are usually valued equally and
+/// the king has no material value.
+///
+/// # Examples
+/// ```
+/// use rchess::PieceType;
+///
+/// assert!(PieceType::Pawn < PieceType::Knight);
+/// assert!(PieceType::Queen < PieceType::King);
+///
+/// let mut pieces = [PieceType::Rook, PieceType::Pawn, PieceType::Queen];
+/// pieces.sort();
+/// assert_eq!(pieces, [PieceType::Pawn, PieceType::Rook, PieceType::Queen]);
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PieceType {
     Pawn,
@@ -164,6 +180,58 @@ impl Piece {
         };
         Some(Self { kind, color })
     }
+
+    /// Gets a [`usize`] used to index arrays by the [`Piece`].
+    ///
+    /// The index is `color.index() * 6 + kind.index()`, so the white pieces come first, each in
+    /// [`PieceType`] order: `0` is a white pawn, `5` is a white king, `6` is a black pawn, and
+    /// `11` is a black king. This layout will not change, so it is safe to use for stored tables.
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::Piece;
+    ///
+    /// assert_eq!(Piece::WHITE_PAWN.index(), 0);
+    /// assert_eq!(Piece::WHITE_KING.index(), 5);
+    /// assert_eq!(Piece::BLACK_PAWN.index(), 6);
+    /// assert_eq!(Piece::BLACK_KING.index(), 11);
+    /// ```
+    #[inline]
+    pub const fn index(&self) -> usize {
+        self.color.index() * 6 + self.kind.index()
+    }
+
+    /// Creates a new [`Piece`] from an index made by [`Piece::index`].
+    ///
+    /// Returns `None` if the index is `12` or more.
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::Piece;
+    ///
+    /// for index in 0..12 {
+    ///     assert_eq!(Piece::from_index(index).unwrap().index(), index);
+    /// }
+    /// assert_eq!(Piece::from_index(7), Some(Piece::BLACK_KNIGHT));
+    /// assert_eq!(Piece::from_index(12), None);
+    /// ```
+    #[inline]
+    pub const fn from_index(index: usize) -> Option<Self> {
+        let color = match index / 6 {
+            0 => Color::White,
+            1 => Color::Black,
+            _ => return None,
+        };
+        let kind = match index % 6 {
+            0 => PieceType::Pawn,
+            1 => PieceType::Knight,
+            2 => PieceType::Bishop,
+            3 => PieceType::Rook,
+            4 => PieceType::Queen,
+            _ => PieceType::King,
+        };
+        Some(Self { kind, color })
+    }
 }
 
 impl Into<Piece> for (PieceType, Color) {
@@ -1,4 +1,5 @@
-use super::Square;
+use super::{Color, Square};
+use core::fmt::{Display, Formatter};
 
 /// All the ranks in order.
 pub const RANKS: [Rank; 8] = [
@@ -75,7 +76,7 @@ impl Rank {
             return None;
         }
         // SAFETY: The index is in a valid range.
-        unsafe { std::mem::transmute(val) }
+        unsafe { core::mem::transmute(val) }
     }
 
     /// Creates a new [`Rank`] from an [`u8`].
@@ -96,7 +97,91 @@ impl Rank {
         match Self::from_index(val) {
             Some(rank) => rank,
             // SAFETY: Caller upholds the safety contract.
-            None => unsafe { std::hint::unreachable_unchecked() },
+            None => unsafe { core::hint::unreachable_unchecked() },
         }
     }
+
+    /// Moves the [`Rank`] up by one.
+    ///
+    /// If the [`Rank`] is the eighth rank, a `None` value is returned.
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::Rank;
+    ///
+    /// assert_eq!(Rank::First.up(), Some(Rank::Second));
+    /// assert_eq!(Rank::Eighth.up(), None);
+    /// ```
+    #[inline]
+    pub const fn up(self) -> Option<Self> {
+        Self::from_index(self.to_u8() + 1)
+    }
+
+    /// Moves the [`Rank`] down by one.
+    ///
+    /// If the [`Rank`] is the first rank, a `None` value is returned.
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::Rank;
+    ///
+    /// assert_eq!(Rank::Eighth.down(), Some(Rank::Seventh));
+    /// assert_eq!(Rank::First.down(), None);
+    /// ```
+    #[inline]
+    pub const fn down(self) -> Option<Self> {
+        if self.to_u8() == 0 {
+            return None;
+        }
+        Self::from_index(self.to_u8() - 1)
+    }
+
+    /// Gets the [`Rank`] as seen from the perspective of the given [`Color`].
+    ///
+    /// The [`Rank`] is unchanged for white and flipped vertically for black, so the first rank
+    /// relative to black is the eighth rank.
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::{Color, Rank};
+    ///
+    /// assert_eq!(Rank::Second.relative_to(Color::White), Rank::Second);
+    /// assert_eq!(Rank::Second.relative_to(Color::Black), Rank::Seventh);
+    /// assert_eq!(Rank::Eighth.relative_to(Color::Black), Rank::First);
+    /// ```
+    #[inline]
+    pub const fn relative_to(self, color: Color) -> Self {
+        match color {
+            Color::White => self,
+            // SAFETY: 7 minus a valid rank index is a valid rank index.
+            Color::Black => unsafe { Self::from_u8_unchecked(7 - self.to_u8()) },
+        }
+    }
+}
+
+impl Display for Rank {
+    /// Displays the [`Rank`] as a number, like in algebraic chess notation.
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::Rank;
+    ///
+    /// assert_eq!(&Rank::First.to_string(), "1");
+    /// assert_eq!(&Rank::Eighth.to_string(), "8");
+    /// ```
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let c = match self {
+            Rank::First => '1',
+            Rank::Second => '2',
+            Rank::Third => '3',
+            Rank::Fourth => '4',
+            Rank::Fifth => '5',
+            Rank::Sixth => '6',
+            Rank::Seventh => '7',
+            Rank::Eighth => '8',
+        };
+
+        write!(f, "{}", c)
+    }
 }
@@ -1,5 +1,5 @@
 use super::{BitBoard, Color, File, Rank};
-use std::fmt::{Display, Formatter};
+use core::fmt::{Display, Formatter};
 
 /// All the squares in order.
 #[rustfmt::skip]
@@ -14,6 +14,30 @@ pub const SQUARES: [Square;64] = [
     Square::A8, Square::B8, Square::C8, Square::D8, Square::E8, Square::F8, Square::G8, Square::H8
 ];
 
+/// The file and rank offsets of a knight move.
+const KNIGHT_OFFSETS: [(i8, i8); 8] = [
+    (-2, 1),
+    (2, 1),
+    (-2, -1),
+    (2, -1),
+    (-1, 2),
+    (-1, -2),
+    (1, 2),
+    (1, -2),
+];
+
+/// The file and rank offsets of a king move.
+const KING_OFFSETS: [(i8, i8); 8] = [
+    (0, -1),
+    (0, 1),
+    (-1, 0),
+    (1, 0),
+    (-1, -1),
+    (1, -1),
+    (-1, 1),
+    (1, 1),
+];
+
 /// The [`Square`] enum represents a square of the chess board.
 ///
 /// Little-Endian Rank File mapping is used for square enumerations.
@@ -68,7 +92,7 @@ impl Square {
             return None;
         }
         // SAFETY: The val is in a valid range.
-        unsafe { Some(std::mem::transmute(val)) }
+        unsafe { Some(core::mem::transmute(val)) }
     }
 
     /// Creates a new [`Square`] from its little-endian rank-file [`u8`] index.
@@ -94,7 +118,7 @@ impl Square {
         match Self::from_u8(val) {
             Some(square) => square,
             // SAFETY: Caller upholds the safety contract.
-            None => unsafe { std::hint::unreachable_unchecked() },
+            None => unsafe { core::hint::unreachable_unchecked() },
         }
     }
 
@@ -243,6 +267,87 @@ impl Square {
         }
     }
 
+    /// Gets the [`Square`] as seen from the perspective of the given [`Color`].
+    ///
+    /// The [`Square`] is unchanged for white and flipped vertically for black, so the relative
+    /// rank of a pawn about to promote is always [`Rank::Seventh`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::{Color, Rank, Square};
+    ///
+    /// assert_eq!(Square::E2.relative_to(Color::White), Square::E2);
+    /// assert_eq!(Square::E2.relative_to(Color::Black), Square::E7);
+    /// assert_eq!(Square::A8.relative_to(Color::Black), Square::A1);
+    ///
+    /// // A black pawn about to promote.
+    /// assert_eq!(Square::C2.relative_to(Color::Black).rank(), Rank::Seventh);
+    /// ```
+    #[inline]
+    pub const fn relative_to(self, color: Color) -> Self {
+        match color {
+            Color::White => self,
+            // SAFETY: Flipping the rank bits of a valid square index gives a valid square index.
+            Color::Black => unsafe { Self::from_u8_unchecked(self.as_u8() ^ 56) },
+        }
+    }
+
+    /// Moves the [`Square`] by the given number of files and ranks.
+    ///
+    /// Positive deltas move right and up. If the result is off the board, a `None` value is
+    /// returned.
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::Square;
+    ///
+    /// assert_eq!(Square::B1.offset(1, 2), Some(Square::C3));
+    /// assert_eq!(Square::E5.offset(-4, -4), Some(Square::A1));
+    /// assert_eq!(Square::H8.offset(1, 0), None);
+    /// ```
+    #[inline]
+    pub const fn offset(self, file_delta: i8, rank_delta: i8) -> Option<Self> {
+        let file = self.file() as i16 + file_delta as i16;
+        let rank = self.rank() as i16 + rank_delta as i16;
+        if file < 0 || file > 7 || rank < 0 || rank > 7 {
+            return None;
+        }
+        // SAFETY: The range checks above guarantee that rank * 8 + file is less than 64.
+        unsafe { Some(Self::from_u8_unchecked((rank * 8 + file) as u8)) }
+    }
+
+    /// Gets an iterator over the squares a knight on the [`Square`] could move to.
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::Square;
+    ///
+    /// let neighbors: Vec<Square> = Square::A1.knight_neighbors().collect();
+    /// assert_eq!(neighbors, [Square::C2, Square::B3]);
+    /// ```
+    #[inline]
+    pub fn knight_neighbors(self) -> impl Iterator<Item = Square> {
+        KNIGHT_OFFSETS
+            .into_iter()
+            .filter_map(move |(file_delta, rank_delta)| self.offset(file_delta, rank_delta))
+    }
+
+    /// Gets an iterator over the squares next to the [`Square`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::Square;
+    ///
+    /// assert_eq!(Square::A1.king_neighbors().count(), 3);
+    /// assert_eq!(Square::E5.king_neighbors().count(), 8);
+    /// ```
+    #[inline]
+    pub fn king_neighbors(self) -> impl Iterator<Item = Square> {
+        KING_OFFSETS
+            .into_iter()
+            .filter_map(move |(file_delta, rank_delta)| self.offset(file_delta, rank_delta))
+    }
+
     /// Moves the [`Square`] up one rank.
     ///
     /// If the [`Square`] is on the eighth rank, a `None` value is returned.
@@ -322,6 +427,45 @@ impl Square {
         }
         unsafe { Some(Self::from_u8_unchecked(self.as_u8() + 1)) }
     }
+
+    /// Gets the squares between this [`Square`] and another, in order from this [`Square`].
+    ///
+    /// Neither [`Square`] is included. If the squares do not share a rank, file or diagonal, an
+    /// empty [`Vec`] is returned.
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::Square;
+    ///
+    /// assert_eq!(Square::F6.squares_between(Square::B2), [Square::E5, Square::D4, Square::C3]);
+    /// assert_eq!(Square::E1.squares_between(Square::E2), []);
+    /// assert_eq!(Square::A1.squares_between(Square::B3), []);
+    /// ```
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn squares_between(self, other: Square) -> Vec<Square> {
+        use super::Direction;
+
+        let (file_delta, rank_delta) = match Direction::between(self, other) {
+            None => return Vec::new(),
+            Some(Direction::Up) => (0, 1),
+            Some(Direction::Down) => (0, -1),
+            Some(Direction::Left) => (-1, 0),
+            Some(Direction::Right) => (1, 0),
+            Some(Direction::UpLeft) => (-1, 1),
+            Some(Direction::UpRight) => (1, 1),
+            Some(Direction::DownLeft) => (-1, -1),
+            Some(Direction::DownRight) => (1, -1),
+        };
+
+        let mut squares = Vec::new();
+        let mut square = self.offset(file_delta, rank_delta).unwrap();
+        while square != other {
+            squares.push(square);
+            square = square.offset(file_delta, rank_delta).unwrap();
+        }
+        squares
+    }
 }
 
 impl Display for Square {
@@ -336,29 +480,7 @@ impl Display for Square {
     /// assert_eq!(&Square::E5.to_string(), "e5");
     /// ```
     #[inline]
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        let file = match self.file() {
-            File::A => 'a',
-            File::B => 'b',
-            File::C => 'c',
-            File::D => 'd',
-            File::E => 'e',
-            File::F => 'f',
-            File::G => 'g',
-            File::H => 'h',
-        };
-
-        let rank = match self.rank() {
-            Rank::First => '1',
-            Rank::Second => '2',
-            Rank::Third => '3',
-            Rank::Fourth => '4',
-            Rank::Fifth => '5',
-            Rank::Sixth => '6',
-            Rank::Seventh => '7',
-            Rank::Eighth => '8',
-        };
-
-        write!(f, "{}{}", file, rank)
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}{}", self.file(), self.rank())
     }
 }
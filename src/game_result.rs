@@ -0,0 +1,78 @@
+use crate::defs::Color;
+use thiserror::Error;
+
+/// The [`GameResult`] enum represents the result of a chess game.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum GameResult {
+    WhiteWins,
+    BlackWins,
+    Draw {
+        reason: DrawReason,
+    },
+
+    /// The given [`Color`] ran out of time and lost the game.
+    TimeForfeit {
+        loser: Color,
+    },
+}
+
+/// The [`DrawReason`] enum represents the thing that caused a draw to occur.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DrawReason {
+    /// Neither side has enough material to checkmate, or a side ran out of time and its opponent
+    /// cannot checkmate.
+    InsufficientMaterial,
+
+    /// The side to move has no legal moves and is not in check.
+    Stalemate,
+
+    /// The same position occurred three times.
+    ThreefoldRepetition,
+
+    /// Fifty moves were made by each side without a capture or pawn move.
+    FiftyMoves,
+
+    /// The same position occurred five times, which draws the game automatically.
+    FivefoldRepetition,
+
+    /// Seventy-five moves were made by each side without a capture or pawn move, which draws the
+    /// game automatically.
+    SeventyFiveMoves,
+
+    /// Both players agreed to a draw.
+    Agreement,
+}
+
+/// The [`TerminalState`] enum represents why the side to move has no legal moves.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TerminalState {
+    /// The side to move is in check, so the given [`Color`] lost the game.
+    Checkmate { loser: Color },
+
+    /// The side to move is not in check, so the game is drawn.
+    Stalemate,
+}
+
+/// The [`MoveOutcome`] enum represents the state of a [`ChessGame`](crate::ChessGame) after a
+/// move was made.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MoveOutcome {
+    /// The game goes on.
+    Ongoing,
+
+    /// The move ended the game with the given [`GameResult`].
+    GameOver { result: GameResult },
+}
+
+/// The [`GameOverError`] struct is the error type for making a move in a
+/// [`ChessGame`](crate::ChessGame) that is already over.
+///
+/// It holds the [`GameResult`] of the game.
+#[derive(Error, Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[error("the game is already over")]
+pub struct GameOverError(pub GameResult);
@@ -1,13 +1,40 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub mod attacks;
+#[cfg(feature = "std")]
 mod chess_game;
 mod chessboard;
 mod defs;
+mod game_result;
 mod mask_gen;
+#[cfg(feature = "std")]
+mod polyglot;
+#[cfg(feature = "std")]
+pub mod uci;
+mod zobrist_gen;
+
+#[cfg(feature = "std")]
+pub use chess_game::ChessGame;
+
+/// The random number generator used by [`ChessGame::random_move`] and [`MoveGen::random_legal`].
+///
+/// It is re-exported so callers can create a `fastrand::Rng` without depending on the same
+/// version of `fastrand` themselves.
+pub use fastrand;
+
+pub use game_result::{DrawReason, GameOverError, GameResult, MoveOutcome, TerminalState};
+
+#[cfg(feature = "std")]
+pub use polyglot::{PolyglotBook, PolyglotError};
 
-pub use chess_game::{ChessGame, DrawReason, GameResult};
+#[cfg(feature = "std")]
+pub use chessboard::{PerftMismatch, PerftSuiteError};
 
 pub use chessboard::{
-    BoardBuilder, BoardBuilderError, BuilderConversionError, ChessBoard, FenFormatError,
-    FenLoadError, Move, MoveCreationError, MoveGen, StrMoveCreationError, ZobristHash,
+    BoardBuilder, BoardBuilderError, BuilderConversionError, CheckType, ChessBoard, DynamicZobrist,
+    FenFormatError, FenLoadError, Footprint, IllegalMoveError, Imbalance, Move, MoveCreationError,
+    MoveGen, PerftStats, PieceMoves, PieceValues, PositionError, StrMoveCreationError,
+    StrMovesError, Transform, ZobristHash,
 };
 
 pub use defs::{
@@ -0,0 +1,163 @@
+use crate::{ChessBoard, Move, MoveGen, Square};
+use std::path::Path;
+use thiserror::Error;
+
+/// The size of an entry in a Polyglot book.
+const ENTRY_SIZE: usize = 16;
+
+/// The [`PolyglotError`] enum is the error type for loading a [`PolyglotBook`].
+#[derive(Error, Debug)]
+pub enum PolyglotError {
+    #[error("the book could not be read")]
+    Io(#[from] std::io::Error),
+
+    #[error("the book length is not a multiple of 16 bytes")]
+    InvalidLength,
+}
+
+/// An entry of a [`PolyglotBook`].
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+struct PolyglotEntry {
+    key: u64,
+    mv: u16,
+    weight: u16,
+}
+
+/// The [`PolyglotBook`] struct is an opening book in the Polyglot `.bin` format.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct PolyglotBook {
+    entries: Vec<PolyglotEntry>,
+}
+
+impl PolyglotBook {
+    /// Creates a new [`PolyglotBook`] from the bytes of a `.bin` file.
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::PolyglotBook;
+    ///
+    /// // An empty book.
+    /// let book = PolyglotBook::from_bytes(&[]).unwrap();
+    /// assert!(book.is_empty());
+    ///
+    /// // A truncated book.
+    /// assert!(PolyglotBook::from_bytes(&[0; 15]).is_err());
+    /// ```
+    #[inline]
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, PolyglotError> {
+        if !bytes.len().is_multiple_of(ENTRY_SIZE) {
+            return Err(PolyglotError::InvalidLength);
+        }
+
+        let mut entries = bytes
+            .chunks_exact(ENTRY_SIZE)
+            .map(|chunk| PolyglotEntry {
+                key: u64::from_be_bytes(chunk[0..8].try_into().unwrap()),
+                mv: u16::from_be_bytes(chunk[8..10].try_into().unwrap()),
+                weight: u16::from_be_bytes(chunk[10..12].try_into().unwrap()),
+            })
+            .collect::<Vec<_>>();
+
+        // Books are sorted by key, but do not rely on it.
+        entries.sort_by_key(|entry| entry.key);
+
+        Ok(Self { entries })
+    }
+
+    /// Reads a [`PolyglotBook`] from a `.bin` file.
+    #[inline]
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, PolyglotError> {
+        let bytes = std::fs::read(path)?;
+        Self::from_bytes(&bytes)
+    }
+
+    /// Gets the number of entries in the [`PolyglotBook`].
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if the [`PolyglotBook`] has no entries.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Gets the book moves and their weights for a [`ChessBoard`].
+    ///
+    /// The board is looked up by its [`ChessBoard::polyglot_key`]. Book moves that are not legal on
+    /// the [`ChessBoard`] are skipped.
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::{ChessBoard, MoveGen, PolyglotBook};
+    ///
+    /// let board = ChessBoard::new();
+    ///
+    /// // A book with the move "e2e4" for the starting position.
+    /// let mut bytes = board.polyglot_key().to_be_bytes().to_vec();
+    /// bytes.extend(0x031Cu16.to_be_bytes());
+    /// bytes.extend(10u16.to_be_bytes());
+    /// bytes.extend(0u32.to_be_bytes());
+    /// let book = PolyglotBook::from_bytes(&bytes).unwrap();
+    ///
+    /// let e2e4 = MoveGen::create_str_move(&board, "e2e4").unwrap();
+    /// assert_eq!(book.lookup(&board), vec![(e2e4, 10)]);
+    /// ```
+    #[inline]
+    pub fn lookup(&self, chessboard: &ChessBoard) -> Vec<(Move, u16)> {
+        self.lookup_key(chessboard.polyglot_key(), chessboard)
+    }
+
+    /// Gets the book moves and their weights for a Polyglot key.
+    ///
+    /// This is the low-level version of [`PolyglotBook::lookup`]. The key must be the Polyglot key
+    /// of the given [`ChessBoard`], which is used to turn the book moves into [`Move`]s. Book moves
+    /// that are not legal on the [`ChessBoard`] are skipped.
+    ///
+    /// # Examples
+    /// ```
+    /// use rchess::{ChessBoard, MoveGen, PolyglotBook};
+    ///
+    /// // The Polyglot key of the starting position.
+    /// let key: u64 = 0x463B96181691FC9C;
+    ///
+    /// // A book with the move "e2e4" for the starting position.
+    /// let mut bytes = key.to_be_bytes().to_vec();
+    /// bytes.extend(0x031Cu16.to_be_bytes());
+    /// bytes.extend(10u16.to_be_bytes());
+    /// bytes.extend(0u32.to_be_bytes());
+    /// let book = PolyglotBook::from_bytes(&bytes).unwrap();
+    ///
+    /// let board = ChessBoard::new();
+    /// let e2e4 = MoveGen::create_str_move(&board, "e2e4").unwrap();
+    /// assert_eq!(book.lookup_key(key, &board), vec![(e2e4, 10)]);
+    /// ```
+    #[inline]
+    pub fn lookup_key(&self, key: u64, chessboard: &ChessBoard) -> Vec<(Move, u16)> {
+        let first = self.entries.partition_point(|entry| entry.key < key);
+        self.entries[first..]
+            .iter()
+            .take_while(|entry| entry.key == key)
+            .filter_map(|entry| Some((decode_move(entry.mv, chessboard)?, entry.weight)))
+            .collect()
+    }
+}
+
+/// Turns a Polyglot move into a [`Move`] for the given [`ChessBoard`].
+///
+/// Returns `None` if the move is not legal.
+fn decode_move(mv: u16, chessboard: &ChessBoard) -> Option<Move> {
+    // Polyglot puts the end square in the low 6 bits and the start square in the next 6.
+    let start = Square::from_u8((mv >> 6 & 0x3F) as u8)?;
+    let mut end = Square::from_u8((mv & 0x3F) as u8)?;
+
+    // Polyglot castles are encoded as the king capturing its own rook.
+    if let Some(king_end) = MoveGen::castle_king_end(chessboard, start, end) {
+        end = king_end;
+    }
+
+    // The promotion pieces are numbered the same way as in `Move::to_u16`.
+    let packed = start.as_u8() as u16 | (end.as_u8() as u16) << 6 | (mv >> 12 & 7) << 12;
+    Move::from_u16(chessboard, packed)
+}
@@ -8,7 +8,7 @@ use crate::table_gen::general::{AXIS_CONNECTIONS, DIRECT_CONNECTIONS, RAYS};
 use crate::table_gen::leapers::{KING_ATTACKS, KNIGHT_ATTACKS, PAWN_ATTACKS};
 use crate::table_gen::sliders::{BISHOP_ATTACKS, BISHOP_MAGICS, ROOK_ATTACKS, ROOK_MAGICS};
 use crate::table_gen::zobrist::{
-    CASTLE_RIGHTS_ZOBRIST, EN_PASSANT_ZOBRIST, PIECE_ZOBRIST, TURN_ZOBRIST,
+    CASTLE_RIGHTS_ZOBRIST, EN_PASSANT_ZOBRIST, HALFMOVE_ZOBRIST, PIECE_ZOBRIST, TURN_ZOBRIST,
 };
 use std::fs::File;
 use std::io::Write;
@@ -93,7 +93,13 @@ fn generate_magic_tables(f: &mut File) {
 
 /// Writes all zobrist numbers to a file.
 pub fn generate_zobrist(f: &mut File) {
-    write_tables!(f, PIECE_ZOBRIST, CASTLE_RIGHTS_ZOBRIST, EN_PASSANT_ZOBRIST);
+    write_tables!(
+        f,
+        PIECE_ZOBRIST,
+        CASTLE_RIGHTS_ZOBRIST,
+        EN_PASSANT_ZOBRIST,
+        HALFMOVE_ZOBRIST
+    );
     writeln!(f, "const TURN_ZOBRIST: u64 = {};", *TURN_ZOBRIST).unwrap();
 }
 
@@ -54,8 +54,8 @@ fn generate_bishop_magics() -> (Box<[Magic; 64]>, usize) {
 
         let mask = get_bishop_mask(square);
         let shift = 64 - BISHOP_BITS[square.index()];
-        let occupancy_attacks = Occupancies::new(mask)
-            .into_iter()
+        let occupancy_attacks = mask
+            .subsets()
             .map(|occupancy| (occupancy, get_bishop_attacks_slow(&RAYS, square, occupancy)))
             .collect::<Vec<_>>();
 
@@ -101,8 +101,8 @@ fn generate_rook_magics() -> (Box<[Magic; 64]>, usize) {
 
         let shift = 64 - ROOK_BITS[square.index()];
         let mask = get_rook_mask(square);
-        let occupancy_attacks = Occupancies::new(mask)
-            .into_iter()
+        let occupancy_attacks = mask
+            .subsets()
             .map(|occupancy| (occupancy, get_rook_attacks_slow(&RAYS, square, occupancy)))
             .collect::<Vec<_>>();
 
@@ -147,8 +147,7 @@ fn generate_bishop_attacks() -> Vec<BitBoard> {
     let mut bishop_attacks = vec![BitBoard::EMPTY; BISHOP_MAGICS.1];
 
     for square in SQUARES {
-        let blocker_occupancies = Occupancies::new(get_bishop_mask(square));
-        for occupancy in blocker_occupancies {
+        for occupancy in get_bishop_mask(square).subsets() {
             let attacks = get_bishop_attacks_slow(&RAYS, square, occupancy);
             let key = BISHOP_MAGICS.0[square.index()].key(occupancy);
             bishop_attacks[key] = attacks;
@@ -162,8 +161,7 @@ fn generate_rook_attacks() -> Vec<BitBoard> {
     let mut rook_attacks = vec![BitBoard::EMPTY; ROOK_MAGICS.1];
 
     for square in SQUARES {
-        let blocker_occupancies = Occupancies::new(get_rook_mask(square));
-        for occupancy in blocker_occupancies {
+        for occupancy in get_rook_mask(square).subsets() {
             let attacks = get_rook_attacks_slow(&RAYS, square, occupancy);
             let key = ROOK_MAGICS.0[square.index()].key(occupancy);
             rook_attacks[key] = attacks;
@@ -207,39 +205,3 @@ fn get_rook_mask(square: Square) -> BitBoard {
 
     mask
 }
-
-/// The [`Occupancies`] struct iterates through all occupancies for a mask.
-pub struct Occupancies {
-    mask: BitBoard,
-    n_combinations: u16,
-    current_combination: u16,
-}
-
-impl Occupancies {
-    /// Creates a new [`Occupancies`] object for a given mask.
-    pub fn new(mask: BitBoard) -> Self {
-        Self {
-            mask,
-            n_combinations: 2u16.pow(mask.popcnt() as u32),
-            current_combination: 0,
-        }
-    }
-}
-
-impl Iterator for Occupancies {
-    type Item = BitBoard;
-
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.current_combination == self.n_combinations {
-            return None;
-        }
-        let mut mask_combination = BitBoard::EMPTY;
-        for (idx, sq) in self.mask.into_iter().enumerate() {
-            if self.current_combination & (1 << idx as u16) != 0 {
-                mask_combination |= sq.bitboard();
-            }
-        }
-        self.current_combination += 1;
-        Some(mask_combination)
-    }
-}
@@ -0,0 +1,95 @@
+//! Helpers for the Universal Chess Interface (UCI) protocol.
+//!
+//! These functions handle the parts of the protocol that depend on the board: loading positions
+//! from `position` commands and writing moves in coordinate notation.
+
+use crate::{ChessBoard, FenLoadError, Move, MoveGen, StrMoveCreationError};
+use thiserror::Error;
+
+/// The [`UciError`] enum is the error type for parsing UCI commands.
+#[derive(Error, Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum UciError {
+    #[error("the command is not a position command")]
+    NotPosition,
+
+    #[error("the position must be startpos or fen")]
+    InvalidPosition,
+
+    #[error("there was an error while loading the fen")]
+    Fen(#[from] FenLoadError),
+
+    #[error("there was an error while making a move")]
+    Move(#[from] StrMoveCreationError),
+}
+
+/// Creates a [`ChessBoard`] from a UCI `position` command.
+///
+/// Both `position startpos` and `position fen <fen>` are supported, each optionally followed by
+/// `moves` and a list of moves in coordinate notation.
+///
+/// # Examples
+/// ```
+/// use rchess::{uci, ChessBoard};
+///
+/// let board = uci::parse_position("position startpos moves e2e4 e7e5").unwrap();
+/// let expected =
+///     ChessBoard::from_fen("rnbqkbnr/pppp1ppp/8/4p3/4P3/8/PPPP1PPP/RNBQKBNR w KQkq e6 0 2")
+///         .unwrap();
+/// assert_eq!(board, expected);
+///
+/// assert!(uci::parse_position("go depth 5").is_err());
+/// ```
+#[inline]
+pub fn parse_position(line: &str) -> Result<ChessBoard, UciError> {
+    let mut tokens = line.split_whitespace();
+
+    if tokens.next() != Some("position") {
+        return Err(UciError::NotPosition);
+    }
+
+    let mut board = match tokens.next() {
+        Some("startpos") => {
+            // Only a moves list may follow the start position.
+            match tokens.next() {
+                None | Some("moves") => {}
+                Some(_) => return Err(UciError::InvalidPosition),
+            }
+            ChessBoard::new()
+        }
+        Some("fen") => {
+            let fen = tokens
+                .by_ref()
+                .take_while(|token| *token != "moves")
+                .collect::<Vec<_>>()
+                .join(" ");
+            ChessBoard::from_fen(&fen)?
+        }
+        _ => return Err(UciError::InvalidPosition),
+    };
+
+    for token in tokens {
+        let mv = MoveGen::create_str_move(&board, token)?;
+        board.make_move(mv);
+    }
+
+    Ok(board)
+}
+
+/// Formats a [`Move`] in UCI coordinate notation.
+///
+/// Promotion pieces are always written in lowercase, as the protocol requires. This is the same
+/// as [`Move::to_uci`].
+///
+/// # Examples
+/// ```
+/// use rchess::{uci, ChessBoard, MoveGen};
+///
+/// let board = ChessBoard::from_fen("8/4P1k1/8/8/8/8/8/4K3 w - - 0 1").unwrap();
+/// let mv = MoveGen::create_str_move(&board, "e7e8n").unwrap();
+/// assert_eq!(uci::format_move(mv), "e7e8n");
+/// ```
+#[inline]
+pub fn format_move(mv: Move) -> String {
+    mv.to_uci()
+}
@@ -0,0 +1,62 @@
+//! Zobrist random number generation.
+//!
+//! This is shared by the build script, which generates the built-in hash numbers from fixed
+//! seeds, and [`DynamicZobrist`](crate::DynamicZobrist), which generates them at runtime. Do not
+//! change the generation order, as that would change the built-in hash numbers.
+
+use fastrand::Rng;
+
+/// Generates piece zobrist random numbers.
+pub fn generate_piece_zobrist(seed: u64) -> [[[u64; 64]; 6]; 2] {
+    let mut piece_zobrist = [[[0; 64]; 6]; 2];
+    let mut rng = Rng::with_seed(seed);
+
+    piece_zobrist.iter_mut().for_each(|j| {
+        j.iter_mut()
+            .for_each(|k| k.iter_mut().for_each(|val| *val = rng.u64(0..=u64::MAX)))
+    });
+
+    piece_zobrist
+}
+
+/// Generates castle right zobrist random numbers.
+pub fn generate_castle_right_zobrist(seed: u64) -> [[u64; 2]; 2] {
+    let mut castle_right_zobrist = [[0; 2]; 2];
+    let mut rng = Rng::with_seed(seed);
+
+    castle_right_zobrist
+        .iter_mut()
+        .for_each(|i| i.iter_mut().for_each(|val| *val = rng.u64(0..=u64::MAX)));
+
+    castle_right_zobrist
+}
+
+/// Generates en passant zobrist random numbers.
+pub fn generate_en_passant_zobrist(seed: u64) -> [u64; 8] {
+    let mut en_passant_zobrist = [0; 8];
+    let mut rng = Rng::with_seed(seed);
+
+    en_passant_zobrist
+        .iter_mut()
+        .for_each(|val| *val = rng.u64(0..=u64::MAX));
+
+    en_passant_zobrist
+}
+
+/// Generates turn zobrist random number.
+pub fn generate_turn_zobrist(seed: u64) -> u64 {
+    let mut rng = Rng::with_seed(seed);
+    rng.u64(0..=u64::MAX)
+}
+
+/// Generates halfmove clock zobrist random numbers.
+pub fn generate_halfmove_zobrist(seed: u64) -> [u64; 256] {
+    let mut halfmove_zobrist = [0; 256];
+    let mut rng = Rng::with_seed(seed);
+
+    halfmove_zobrist
+        .iter_mut()
+        .for_each(|val| *val = rng.u64(0..=u64::MAX));
+
+    halfmove_zobrist
+}
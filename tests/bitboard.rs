@@ -0,0 +1,111 @@
+mod common;
+
+use common::random_bitboards;
+use rchess::{BitBoard, File, Rank, Square, FILES, RANKS, SQUARES};
+
+#[test]
+fn subsets() {
+    for bitboard in [
+        BitBoard::EMPTY,
+        Square::E4.bitboard(),
+        BitBoard::from_rank(Rank::Second),
+        BitBoard::from_file(File::A) | BitBoard::from_rank(Rank::Eighth),
+        BitBoard::from_squares(&[Square::B2, Square::D5, Square::G7, Square::H1]),
+    ] {
+        let subsets = bitboard.subsets().collect::<Vec<_>>();
+        assert_eq!(subsets.len(), 1 << bitboard.popcnt());
+
+        // Every subset is distinct and only uses the squares of the bitboard.
+        let mut values = subsets.iter().map(|bb| bb.to_u64()).collect::<Vec<_>>();
+        values.sort();
+        values.dedup();
+        assert_eq!(values.len(), subsets.len());
+        assert!(subsets
+            .iter()
+            .all(|subset| (*subset & !bitboard).is_empty()));
+    }
+}
+
+#[test]
+fn subsets_match_index_enumeration() {
+    // Builds each subset from the bits of a counter, one mask square at a time.
+    fn index_subsets(mask: BitBoard) -> Vec<u64> {
+        (0..1u32 << mask.popcnt())
+            .map(|combination| {
+                mask.into_iter()
+                    .enumerate()
+                    .filter(|(idx, _)| combination & (1 << idx) != 0)
+                    .fold(0, |subset, (_, sq)| subset | sq.bitboard().to_u64())
+            })
+            .collect()
+    }
+
+    for mask in random_bitboards(1) {
+        let mut subsets = mask.subsets().map(|bb| bb.to_u64()).collect::<Vec<_>>();
+        let mut expected = index_subsets(mask);
+        subsets.sort();
+        expected.sort();
+        assert_eq!(subsets, expected);
+    }
+}
+
+#[test]
+fn squares_between() {
+    for a in SQUARES {
+        for b in SQUARES {
+            let squares = a.squares_between(b);
+            assert_eq!(BitBoard::from_squares(&squares), BitBoard::between(a, b));
+
+            // The squares are ordered away from the first square.
+            let mut reversed = b.squares_between(a);
+            reversed.reverse();
+            assert_eq!(squares, reversed);
+        }
+    }
+}
+
+#[test]
+fn single() {
+    for square in SQUARES {
+        assert!(square.bitboard().is_single());
+        assert_eq!(square.bitboard().single(), Some(square));
+    }
+
+    for bitboard in random_bitboards(2) {
+        assert_eq!(bitboard.is_single(), bitboard.popcnt() == 1);
+        assert_eq!(bitboard.single().is_some(), bitboard.is_single());
+    }
+}
+
+#[test]
+fn display_files_and_ranks() {
+    for square in SQUARES {
+        assert_eq!(
+            square.to_string(),
+            format!("{}{}", square.file(), square.rank())
+        );
+    }
+    assert_eq!(
+        FILES.map(|file| file.to_string()).concat(),
+        "abcdefgh".to_string()
+    );
+    assert_eq!(
+        RANKS.map(|rank| rank.to_string()).concat(),
+        "12345678".to_string()
+    );
+}
+
+#[test]
+fn bit_tricks() {
+    for bitboard in random_bitboards(4) {
+        assert_eq!(bitboard.more_than_one(), bitboard.popcnt() > 1);
+
+        let lowest = bitboard.b_scan_forward().map(|sq| sq.bitboard());
+        let highest = bitboard.b_scan_reverse().map(|sq| sq.bitboard());
+        assert_eq!(bitboard.lsb(), lowest.unwrap_or(BitBoard::EMPTY));
+        assert_eq!(bitboard.msb(), highest.unwrap_or(BitBoard::EMPTY));
+    }
+
+    assert_eq!(BitBoard::FULL.lsb(), Square::A1.bitboard());
+    assert_eq!(BitBoard::FULL.msb(), Square::H8.bitboard());
+}
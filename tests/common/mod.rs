@@ -0,0 +1,21 @@
+//! Helpers shared by the integration tests.
+#![allow(dead_code)]
+
+use rchess::{BitBoard, ChessBoard, Move, MoveGen};
+
+/// Calls `f` with every board and legal move in the game tree below `board`, down to `depth` plies.
+pub fn walk(board: &ChessBoard, depth: u8, f: &mut impl FnMut(&ChessBoard, Move)) {
+    if depth == 0 {
+        return;
+    }
+    for mv in MoveGen::legal(board) {
+        f(board, mv);
+        walk(&board.get_child(mv), depth - 1, f);
+    }
+}
+
+/// Returns 200 sparse random bitboards, like the slider blocker masks, seeded with `seed`.
+pub fn random_bitboards(seed: u64) -> impl Iterator<Item = BitBoard> {
+    let mut rng = fastrand::Rng::with_seed(seed);
+    (0..200).map(move |_| BitBoard::from_u64(rng.u64(..) & rng.u64(..) & rng.u64(..)))
+}
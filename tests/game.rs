@@ -1,4 +1,62 @@
-use rchess::{ChessGame, Color, DrawReason, GameResult, Square};
+use rchess::{
+    ChessBoard, ChessGame, Color, DrawReason, GameOverError, GameResult, Move, MoveOutcome,
+    PieceType, Square, TerminalState,
+};
+use std::time::Duration;
+
+/// Walks both rooks around the board without repeating a position three times.
+fn walk_rooks(game: &mut ChessGame, w_rook_sq: &mut Square, b_rook_sq: &mut Square, laps: usize) {
+    for _ in 0..laps {
+        // 12 moves to get down.
+        for _ in 0..12 {
+            let rook_sq = match game.board().turn() {
+                Color::White => &mut *w_rook_sq,
+                Color::Black => &mut *b_rook_sq,
+            };
+
+            let start = *rook_sq;
+            let end = start.down().unwrap();
+            *rook_sq = end;
+
+            let mv = game.create_move(start, end).unwrap();
+            game.make_move(mv).unwrap();
+        }
+
+        // 12 moves to get up.
+        for _ in 0..12 {
+            let rook_sq = match game.board().turn() {
+                Color::White => &mut *w_rook_sq,
+                Color::Black => &mut *b_rook_sq,
+            };
+
+            let start = *rook_sq;
+            let end = start.up().unwrap();
+            *rook_sq = end;
+
+            let mv = game.create_move(start, end).unwrap();
+            game.make_move(mv).unwrap();
+        }
+
+        // Move rook over.
+        let (start, end) = match game.board().turn() {
+            Color::White => {
+                let start = *w_rook_sq;
+                let end = w_rook_sq.right().unwrap();
+                *w_rook_sq = end;
+                (start, end)
+            }
+            Color::Black => {
+                let start = *b_rook_sq;
+                let end = b_rook_sq.left().unwrap();
+                *b_rook_sq = end;
+                (start, end)
+            }
+        };
+
+        let mv = game.create_move(start, end).unwrap();
+        game.make_move(mv).unwrap();
+    }
+}
 
 #[test]
 fn repetition() {
@@ -9,12 +67,48 @@ fn repetition() {
         let mv = game.create_str_move(mv).unwrap();
         game.make_move(mv).unwrap();
     }
+    assert!(game.result().is_none());
+    assert_eq!(game.claimable_draw(), Some(DrawReason::ThreefoldRepetition));
+    assert!(game.claim_draw());
     assert_eq!(
         game.result(),
         Some(GameResult::Draw {
             reason: DrawReason::ThreefoldRepetition
         })
     );
+    assert!(game.moves().is_empty());
+}
+
+#[test]
+fn play_on_after_repetition() {
+    let mut game = ChessGame::new();
+    for mv in [
+        "g1f3", "b8a6", "f3g1", "a6b8", "g1f3", "b8a6", "f3g1", "a6b8", "e2e4",
+    ] {
+        let mv = game.create_str_move(mv).unwrap();
+        game.make_move(mv).unwrap();
+    }
+    assert!(game.result().is_none());
+    assert_eq!(game.claimable_draw(), None);
+}
+
+#[test]
+fn fivefold_repetition() {
+    let mut game = ChessGame::new();
+    for _ in 0..4 {
+        assert!(game.result().is_none());
+        for mv in ["g1f3", "b8a6", "f3g1", "a6b8"] {
+            let mv = game.create_str_move(mv).unwrap();
+            game.make_move(mv).unwrap();
+        }
+    }
+    assert_eq!(
+        game.result(),
+        Some(GameResult::Draw {
+            reason: DrawReason::FivefoldRepetition
+        })
+    );
+    assert!(game.moves().is_empty());
 }
 
 #[test]
@@ -143,71 +237,43 @@ fn fifty_moves() {
     let mut b_rook_sq = Square::G8;
 
     // 4 back & forths.
-    for _ in 0..4 {
-        // 12 moves to get down.
-        for _ in 0..12 {
-            let rook_sq = match game.board().turn() {
-                Color::White => &mut w_rook_sq,
-                Color::Black => &mut b_rook_sq,
-            };
-
-            let start = *rook_sq;
-            let end = start.down().unwrap();
-            *rook_sq = end;
-
-            let mv = game.create_move(start, end).unwrap();
-            game.make_move(mv).unwrap();
-        }
+    walk_rooks(&mut game, &mut w_rook_sq, &mut b_rook_sq, 4);
 
-        // 12 moves to get up.
-        for _ in 0..12 {
-            let rook_sq = match game.board().turn() {
-                Color::White => &mut w_rook_sq,
-                Color::Black => &mut b_rook_sq,
-            };
-
-            let start = *rook_sq;
-            let end = start.up().unwrap();
-            *rook_sq = end;
+    assert!(game.result().is_none());
+    assert_eq!(game.claimable_draw(), Some(DrawReason::FiftyMoves));
+}
 
-            let mv = game.create_move(start, end).unwrap();
-            game.make_move(mv).unwrap();
-        }
+#[test]
+fn seventy_five_moves() {
+    let mut game = ChessGame::from_fen("1R4r1/8/8/8/8/8/8/K6k w - - 100").unwrap();
+    let mut w_rook_sq = Square::B8;
+    let mut b_rook_sq = Square::G8;
 
-        // Move rook over.
-        let (start, end) = match game.board().turn() {
-            Color::White => {
-                let start = w_rook_sq;
-                let end = w_rook_sq.right().unwrap();
-                w_rook_sq = end;
-                (start, end)
-            }
-            Color::Black => {
-                let start = b_rook_sq;
-                let end = b_rook_sq.left().unwrap();
-                b_rook_sq = end;
-                (start, end)
-            }
-        };
+    // 2 back & forths.
+    walk_rooks(&mut game, &mut w_rook_sq, &mut b_rook_sq, 2);
 
-        let mv = game.create_move(start, end).unwrap();
-        game.make_move(mv).unwrap();
-    }
     assert_eq!(
         game.result(),
         Some(GameResult::Draw {
-            reason: DrawReason::FiftyMoves
+            reason: DrawReason::SeventyFiveMoves
         })
     );
 }
 
 #[test]
 fn loaded_halfmoves() {
-    let game = ChessGame::from_fen("7k/8/1r6/8/8/6R1/8/K7 w - - 100").unwrap();
+    for halfmoves in [100, 101] {
+        let fen = format!("7k/8/1r6/8/8/6R1/8/K7 w - - {halfmoves}");
+        let game = ChessGame::from_fen(&fen).unwrap();
+        assert!(game.result().is_none());
+        assert_eq!(game.claimable_draw(), Some(DrawReason::FiftyMoves));
+    }
+
+    let game = ChessGame::from_fen("7k/8/1r6/8/8/6R1/8/K7 w - - 150").unwrap();
     assert_eq!(
         game.result(),
         Some(GameResult::Draw {
-            reason: DrawReason::FiftyMoves
+            reason: DrawReason::SeventyFiveMoves
         })
     );
 }
@@ -218,6 +284,7 @@ fn halfmove_reset() {
     game.make_move(game.create_str_move("a3a4").unwrap())
         .unwrap();
     assert!(game.result().is_none());
+    assert_eq!(game.claimable_draw(), None);
 }
 
 #[test]
@@ -225,10 +292,313 @@ fn halfmove_not_reset() {
     let mut game = ChessGame::from_fen("8/8/8/8/8/RK3k2/8/8 w - - 99").unwrap();
     game.make_move(game.create_str_move("a3a4").unwrap())
         .unwrap();
+    assert_eq!(game.claimable_draw(), Some(DrawReason::FiftyMoves));
+}
+
+#[test]
+fn loaded_halfmoves_repetition_history() {
+    let mut game = ChessGame::from_fen("7k/7p/8/8/8/8/8/KN6 w - - 99").unwrap();
+    assert_eq!(game.claimable_draw(), None);
+    game.make_move(game.create_str_move("b1c3").unwrap())
+        .unwrap();
+    assert_eq!(game.board().halfmoves(), 100);
+    assert_eq!(game.claimable_draw(), Some(DrawReason::FiftyMoves));
+    assert!(game.claim_draw());
+    assert_eq!(
+        game.result(),
+        Some(GameResult::Draw {
+            reason: DrawReason::FiftyMoves
+        })
+    );
+}
+
+#[test]
+fn resign() {
+    let mut game = ChessGame::new();
+    let mv = game.create_str_move("e2e4").unwrap();
+    game.make_move(mv).unwrap();
+    game.resign(Color::Black);
+    assert_eq!(game.result(), Some(GameResult::WhiteWins));
+    assert!(game.moves().is_empty());
+    assert!(game.create_str_move("e7e5").is_err());
+    assert!(game.make_move(mv).is_err());
+
+    // The result cannot be changed once the game is over.
+    game.resign(Color::White);
+    game.agree_draw();
+    assert_eq!(game.result(), Some(GameResult::WhiteWins));
+}
+
+#[test]
+fn agree_draw() {
+    let mut game = ChessGame::new();
+    let mv = game.create_str_move("e2e4").unwrap();
+    game.agree_draw();
     assert_eq!(
         game.result(),
+        Some(GameResult::Draw {
+            reason: DrawReason::Agreement
+        })
+    );
+    assert!(game.make_move(mv).is_err());
+}
+
+#[test]
+fn positions() {
+    let mut game =
+        ChessGame::from_fen("rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq - 0 1").unwrap();
+    for mv in ["e7e5", "g1f3", "b8c6"] {
+        let mv = game.create_str_move(mv).unwrap();
+        game.make_move(mv).unwrap();
+    }
+
+    let fens: Vec<String> = game.positions().iter().map(|b| b.get_fen()).collect();
+    assert_eq!(
+        fens,
+        [
+            "rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq -",
+            "rnbqkbnr/pppp1ppp/8/4p3/4P3/8/PPPP1PPP/RNBQKBNR w KQkq e6",
+            "rnbqkbnr/pppp1ppp/8/4p3/4P3/5N2/PPPP1PPP/RNBQKB1R b KQkq -",
+            "r1bqkbnr/pppp1ppp/2n5/4p3/4P3/5N2/PPPP1PPP/RNBQKB1R w KQkq -",
+        ]
+    );
+}
+
+#[test]
+fn board_outcome() {
+    // The board agrees with the game when no history is needed.
+    for fen in [
+        "1r5k/8/8/8/8/8/7r/K7 w - -",
+        "R5k1/8/6K1/8/8/8/8/8 b - -",
+        "7k/8/8/8/8/8/8/K7 w - -",
+        "7k/8/8/8/8/8/8/KN6 w - -",
+        "6bk/8/8/8/8/8/8/KB6 w - -",
+        "5b1k/8/8/8/8/8/8/KB6 w - -",
+        "7k/8/8/8/8/8/8/KR6 w - -",
+        "rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq - 0 1",
+    ] {
+        let game = ChessGame::from_fen(fen).unwrap();
+        assert_eq!(game.board().outcome(), game.result(), "{}", fen);
+    }
+
+    // The fifty-move rule is reported by the board, but must be claimed in a game.
+    let game = ChessGame::from_fen("7k/8/1r6/8/8/6R1/8/K7 w - - 100").unwrap();
+    assert_eq!(game.result(), None);
+    assert_eq!(
+        game.board().outcome(),
         Some(GameResult::Draw {
             reason: DrawReason::FiftyMoves
         })
     );
+
+    // A checkmate on the last move still stands.
+    let board = ChessBoard::from_fen("R5k1/8/6K1/8/8/8/8/8 b - - 100").unwrap();
+    assert_eq!(board.outcome(), Some(GameResult::WhiteWins));
+
+    let mut game = ChessGame::from_fen("1R4r1/8/8/8/8/8/8/K6k w - - 100").unwrap();
+    let mut w_rook_sq = Square::B8;
+    let mut b_rook_sq = Square::G8;
+    walk_rooks(&mut game, &mut w_rook_sq, &mut b_rook_sq, 2);
+    assert_eq!(game.board().outcome(), game.result());
+}
+
+#[test]
+fn legal_moves() {
+    let mut game = ChessGame::from_fen("1r5k/8/8/8/8/8/7r/1K6 w - -").unwrap();
+    assert!(game.legal_moves().eq(game.moves().iter().copied()));
+
+    // Random moves are always legal.
+    let mut rng = fastrand::Rng::with_seed(0);
+    let mv = game.random_move(&mut rng).unwrap();
+    assert!(game.moves().contains(&mv));
+
+    // There are no moves once the game is over.
+    game.agree_draw();
+    assert_eq!(game.legal_moves().count(), 0);
+    assert_eq!(game.random_move(&mut rng), None);
+}
+
+#[test]
+fn terminal_state() {
+    for (fen, expected) in [
+        (
+            "rnb1kbnr/pppp1ppp/8/4p3/6Pq/5P2/PPPPP2P/RNBQKBNR w KQkq - 1 3",
+            Some(TerminalState::Checkmate {
+                loser: Color::White,
+            }),
+        ),
+        (
+            "6rk/5Npp/8/8/8/8/8/K7 b - -",
+            Some(TerminalState::Checkmate {
+                loser: Color::Black,
+            }),
+        ),
+        ("1r5k/8/8/8/8/8/7r/K7 w - -", Some(TerminalState::Stalemate)),
+        ("7k/8/8/8/8/8/8/KR6 b - -", None),
+    ] {
+        let board = ChessBoard::from_fen(fen).unwrap();
+        assert_eq!(board.terminal_state(), expected);
+        assert_eq!(
+            board.is_checkmate(),
+            matches!(expected, Some(TerminalState::Checkmate { .. }))
+        );
+        assert_eq!(
+            board.is_stalemate(),
+            expected == Some(TerminalState::Stalemate)
+        );
+    }
+}
+
+#[test]
+fn clock() {
+    // Games are untimed by default.
+    let mut game = ChessGame::new();
+    game.record_move_time(Duration::from_secs(3600));
+    assert_eq!(game.clock(Color::White), None);
+    assert!(game.result().is_none());
+
+    game.set_clock(Duration::from_secs(60), Duration::from_secs(30));
+    for (mv, elapsed) in [("e2e4", 20), ("e7e5", 10), ("g1f3", 30)] {
+        game.record_move_time(Duration::from_secs(elapsed));
+        let mv = game.create_str_move(mv).unwrap();
+        game.make_move(mv).unwrap();
+    }
+    assert_eq!(game.clock(Color::White), Some(Duration::from_secs(10)));
+    assert_eq!(game.clock(Color::Black), Some(Duration::from_secs(20)));
+
+    // Black's clock hits zero exactly.
+    game.record_move_time(Duration::from_secs(20));
+    assert_eq!(
+        game.result(),
+        Some(GameResult::TimeForfeit {
+            loser: Color::Black
+        })
+    );
+    assert!(game.moves().is_empty());
+
+    // The clocks stop once the game is over.
+    game.record_move_time(Duration::from_secs(5));
+    assert_eq!(game.clock(Color::White), Some(Duration::from_secs(10)));
+    assert_eq!(game.clock(Color::Black), Some(Duration::ZERO));
+
+    // A finished game does not lose on time.
+    let mut game = ChessGame::from_fen("R5k1/8/6K1/8/8/8/8/8 b - -").unwrap();
+    game.set_clock(Duration::from_secs(1), Duration::from_secs(1));
+    game.record_move_time(Duration::from_secs(2));
+    assert_eq!(game.result(), Some(GameResult::WhiteWins));
+
+    // Running out of time is a draw if the opponent cannot checkmate.
+    let mut game = ChessGame::from_fen("7k/8/8/8/8/8/8/KQ6 w - -").unwrap();
+    game.set_clock(Duration::from_secs(1), Duration::from_secs(1));
+    game.record_move_time(Duration::from_secs(2));
+    assert_eq!(
+        game.result(),
+        Some(GameResult::Draw {
+            reason: DrawReason::InsufficientMaterial
+        })
+    );
+
+    let mut game = ChessGame::from_fen("7k/8/8/8/8/8/8/KQ6 b - -").unwrap();
+    game.set_clock(Duration::from_secs(1), Duration::from_secs(1));
+    game.record_move_time(Duration::from_secs(2));
+    assert_eq!(
+        game.result(),
+        Some(GameResult::TimeForfeit {
+            loser: Color::Black
+        })
+    );
+}
+
+#[test]
+fn with_history() {
+    // Shuffle the knights, recording the position before each move.
+    let mut board = ChessBoard::new();
+    let mut prior = vec![];
+    for _ in 0..4 {
+        for mv in ["g1f3", "g8f6", "f3g1", "f6g8"] {
+            prior.push(board.clone());
+            board.apply_uci(mv).unwrap();
+        }
+    }
+    let fen = board.get_fen();
+
+    // Without the history, nothing is repeated yet.
+    let game = ChessGame::from_fen(&fen).unwrap();
+    assert_eq!(game.claimable_draw(), None);
+
+    // Two earlier occurrences make a threefold repetition.
+    let game = ChessGame::with_history(&fen, &prior[..8]).unwrap();
+    assert_eq!(game.claimable_draw(), Some(DrawReason::ThreefoldRepetition));
+    assert_eq!(game.start_board(), &board);
+    assert!(game.made_moves().is_empty());
+
+    // Four earlier occurrences end the game.
+    let game = ChessGame::with_history(&fen, &prior).unwrap();
+    assert_eq!(
+        game.result(),
+        Some(GameResult::Draw {
+            reason: DrawReason::FivefoldRepetition
+        })
+    );
+    assert!(game.moves().is_empty());
+
+    // The history keeps counting after moves are made.
+    let mut game = ChessGame::with_history(&fen, &prior[..4]).unwrap();
+    for mv in ["g1f3", "g8f6", "f3g1", "f6g8"] {
+        assert_eq!(game.claimable_draw(), None);
+        let mv = game.create_str_move(mv).unwrap();
+        game.make_move(mv).unwrap();
+    }
+    assert_eq!(game.claimable_draw(), Some(DrawReason::ThreefoldRepetition));
+}
+
+#[test]
+fn move_outcome() {
+    let mut game = ChessGame::new();
+    for (mv, outcome) in [
+        ("f2f3", MoveOutcome::Ongoing),
+        ("e7e6", MoveOutcome::Ongoing),
+        ("g2g4", MoveOutcome::Ongoing),
+        (
+            "d8h4",
+            MoveOutcome::GameOver {
+                result: GameResult::BlackWins,
+            },
+        ),
+    ] {
+        let mv = game.create_str_move(mv).unwrap();
+        assert_eq!(game.make_move(mv), Ok(outcome));
+    }
+
+    // Moves after the end of the game report the result.
+    let mv = Move::Quiet {
+        start: Square::E1,
+        end: Square::F2,
+        moving: PieceType::King,
+    };
+    assert_eq!(
+        game.make_move(mv),
+        Err(GameOverError(GameResult::BlackWins))
+    );
+
+    // Fivefold repetition ends the game on the repeating move.
+    let mut game = ChessGame::new();
+    let mut outcomes = vec![];
+    for _ in 0..4 {
+        for mv in ["g1f3", "g8f6", "f3g1", "f6g8"] {
+            let mv = game.create_str_move(mv).unwrap();
+            outcomes.push(game.make_move(mv).unwrap());
+        }
+    }
+    assert!(outcomes[..15]
+        .iter()
+        .all(|outcome| *outcome == MoveOutcome::Ongoing));
+    assert_eq!(
+        outcomes[15],
+        MoveOutcome::GameOver {
+            result: GameResult::Draw {
+                reason: DrawReason::FivefoldRepetition
+            }
+        }
+    );
 }
@@ -1,4 +1,8 @@
-use rchess::{ChessBoard, MoveGen};
+mod common;
+
+use common::walk;
+use rchess::{ChessBoard, DynamicZobrist, MoveGen};
+use std::cmp::Ordering;
 
 #[test]
 fn p1() {
@@ -30,3 +34,224 @@ fn p3() {
     assert_eq!(fen, moves);
     assert_eq!(fen.hash(), moves.hash());
 }
+
+#[test]
+fn pawn_and_material_hashes() {
+    let fen = ChessBoard::from_fen("rnbq1bnr/p1ppkppp/p7/4p3/4P3/7N/PPPP1PPP/RNBQ1RK1 b - - 0 1")
+        .unwrap();
+    let moves =
+        ChessBoard::from_str_moves(&["e2e4", "e7e5", "f1a6", "b7a6", "g1h3", "e8e7", "e1g1"])
+            .unwrap();
+    assert_eq!(fen.pawn_hash(), moves.pawn_hash());
+    assert_eq!(fen.material_hash(), moves.material_hash());
+}
+
+#[test]
+fn promotion_material_hash() {
+    let fen = ChessBoard::from_fen("7Q/8/8/8/8/8/k6K/8 b - -").unwrap();
+    let mut moves = ChessBoard::from_fen("7r/6P1/8/8/8/8/k6K/8 w - -").unwrap();
+    moves.make_move(MoveGen::create_str_move(&moves, "g7h8q").unwrap());
+
+    assert_eq!(fen.pawn_hash(), moves.pawn_hash());
+    assert_eq!(fen.material_hash(), moves.material_hash());
+}
+
+#[test]
+fn en_passant_pawn_hash() {
+    let fen = ChessBoard::from_fen("4k3/8/3P4/8/8/8/8/4K3 b - -").unwrap();
+    let mut moves = ChessBoard::from_fen("4k3/3p4/8/4P3/8/8/8/4K3 b - -").unwrap();
+    moves.make_move(MoveGen::create_str_move(&moves, "d7d5").unwrap());
+    moves.make_move(MoveGen::create_str_move(&moves, "e5d6").unwrap());
+
+    assert_eq!(fen.pawn_hash(), moves.pawn_hash());
+    assert_eq!(fen.material_hash(), moves.material_hash());
+}
+
+#[test]
+fn stable_hashes() {
+    assert_eq!(ChessBoard::new().hash().to_u64(), 0x6FAEE8FA1013FD66);
+
+    let board = ChessBoard::from_str_moves(&["e2e4"]).unwrap();
+    assert_eq!(board.hash().to_u64(), 0xCB57D3266745C050);
+}
+
+#[test]
+fn fen_en_passant() {
+    let fen =
+        ChessBoard::from_fen("rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq e3").unwrap();
+    let moves = ChessBoard::from_str_moves(&["e2e4"]).unwrap();
+    assert_eq!(fen, moves);
+    assert_eq!(fen.hash(), moves.hash());
+}
+
+#[test]
+fn normalized_en_passant() {
+    let fen =
+        ChessBoard::from_fen("rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq -").unwrap();
+    let mut moves = ChessBoard::from_str_moves(&["e2e4"]).unwrap();
+    assert_ne!(fen, moves);
+    assert_eq!(fen.hash(), moves.hash());
+
+    moves.normalize_ep();
+    assert_eq!(fen, moves);
+    assert_eq!(fen.hash(), moves.hash());
+}
+
+#[test]
+fn capturable_en_passant() {
+    let mut board = ChessBoard::from_str_moves(&["e2e4", "a7a6", "e4e5", "d7d5"]).unwrap();
+    let hash = board.hash();
+    board.normalize_ep();
+    assert!(board.en_passant_sq().is_some());
+    assert_eq!(board.hash(), hash);
+}
+
+#[test]
+fn uncapturable_en_passant() {
+    // No black pawn can capture on e3, so the en passant square does not affect the hash.
+    let pushed = ChessBoard::from_str_moves(&["e2e4"]).unwrap();
+    let transposed = ChessBoard::from_str_moves(&["e2e4", "g8f6", "g1f3", "f6g8", "f3g1"]).unwrap();
+    assert_ne!(pushed, transposed);
+    assert_eq!(pushed.hash(), transposed.hash());
+
+    // The white pawn on e5 can capture on d6, so the en passant square affects the hash.
+    let pushed = ChessBoard::from_str_moves(&["e2e4", "a7a6", "e4e5", "d7d5"]).unwrap();
+    let fen =
+        ChessBoard::from_fen("rnbqkbnr/1pp1pppp/p7/3pP3/8/8/PPPP1PPP/RNBQKBNR w KQkq -").unwrap();
+    assert_ne!(pushed.hash(), fen.hash());
+
+    let fen =
+        ChessBoard::from_fen("rnbqkbnr/1pp1pppp/p7/3pP3/8/8/PPPP1PPP/RNBQKBNR w KQkq d6").unwrap();
+    assert_eq!(pushed.hash(), fen.hash());
+}
+
+/// These values must not depend on the enabled features, so this test is expected to pass both
+/// with and without `--features magic-table`.
+#[test]
+fn feature_independent() {
+    for (fen, hash, nodes) in [
+        (
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq -",
+            0x6FAEE8FA1013FD66,
+            8902,
+        ),
+        (
+            "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq -",
+            0x1297E1251E98D26F,
+            97862,
+        ),
+        (
+            "8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - -",
+            0x6CA15E1761674314,
+            2812,
+        ),
+        (
+            "r3k2r/Pppp1ppp/1b3nbN/nP6/BBP1P3/q4N2/Pp1P2PP/R2Q1RK1 w kq -",
+            0xCABA61E2BDDE3BBA,
+            9467,
+        ),
+        (
+            "rnbq1k1r/pp1Pbppp/2p5/8/2B5/8/PPP1NnPP/RNBQK2R w KQ -",
+            0x83445C0142151C9A,
+            62379,
+        ),
+        (
+            "r4rk1/1pp1qppp/p1np1n2/2b1p1B1/2B1P1b1/P1NP1N2/1PP1QPPP/R4RK1 w - -",
+            0xAEC1E6E9D3B56700,
+            89890,
+        ),
+        (
+            "rnbqkbnr/1pp1pppp/p7/3pP3/8/8/PPPP1PPP/RNBQKBNR w KQkq d6",
+            0x9475F02D98AA00A3,
+            24166,
+        ),
+    ] {
+        let board = ChessBoard::from_fen(fen).unwrap();
+        assert_eq!(board.hash().to_u64(), hash, "{}", fen);
+        assert_eq!(MoveGen::perft(board, 3), nodes, "{}", fen);
+    }
+}
+
+#[test]
+fn dynamic_zobrist() {
+    let default = DynamicZobrist::default();
+    let seeded = DynamicZobrist::new(1);
+    let other = DynamicZobrist::new(2);
+
+    for fen in [
+        "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+        "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 3 1",
+        "r3k2r/Pppp1ppp/1b3nbN/nP6/BBP1P3/q4N2/Pp1P2PP/R2Q1RK1 w kq - 0 1",
+        "rnbqkbnr/1pp1pppp/p7/3pP3/8/8/PPPP1PPP/RNBQKBNR w KQkq d6 0 3",
+        "rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq e3 0 1",
+    ] {
+        let board = ChessBoard::from_fen(fen).unwrap();
+        assert_eq!(default.hash(&board), board.hash().to_u64(), "{}", fen);
+        assert_eq!(default.full_key(&board), board.full_key(), "{}", fen);
+        assert_ne!(seeded.hash(&board), board.hash().to_u64(), "{}", fen);
+        assert_ne!(seeded.hash(&board), other.hash(&board), "{}", fen);
+    }
+
+    // The seeded hash follows the board through moves.
+    let mut board = ChessBoard::new();
+    for mv in [
+        "e2e4", "d7d5", "e4e5", "f7f5", "e5f6", "e8f7", "g1f3", "g8h6", "f1c4", "b8c6",
+    ] {
+        board.make_move(MoveGen::create_str_move(&board, mv).unwrap());
+        let fen = ChessBoard::from_fen(&board.get_fen()).unwrap();
+        assert_eq!(seeded.hash(&board), seeded.hash(&fen));
+        assert_eq!(default.hash(&board), board.hash().to_u64());
+    }
+    assert_eq!(DynamicZobrist::new(1), seeded);
+}
+
+#[test]
+fn recompute_hash() {
+    fn check(board: &ChessBoard) {
+        assert_eq!(board.recompute_hash(), board.hash(), "{}", board.get_fen());
+    }
+
+    for fen in [
+        "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq -",
+        "8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - -",
+        "r3k2r/Pppp1ppp/1b3nbN/nP6/BBP1P3/q4N2/Pp1P2PP/R2Q1RK1 w kq -",
+        "rnbqkbnr/ppp1p1pp/8/3pPp2/8/8/PPPP1PPP/RNBQKBNR w KQkq f6 0 3",
+    ] {
+        let board = ChessBoard::from_fen(fen).unwrap();
+        check(&board);
+        walk(&board, 3, &mut |board, mv| check(&board.get_child(mv)));
+    }
+}
+
+#[test]
+fn ordering() {
+    let mut rng = fastrand::Rng::with_seed(5);
+
+    // Collect the boards of a few random games.
+    let mut boards = Vec::new();
+    for _ in 0..20 {
+        let mut board = ChessBoard::new();
+        for _ in 0..10 {
+            boards.push(board.clone());
+            match MoveGen::random_legal(&board, &mut rng) {
+                Some(mv) => board.make_move(mv),
+                None => break,
+            }
+        }
+    }
+
+    // The order agrees with equality.
+    for a in &boards {
+        for b in &boards {
+            assert_eq!(a.cmp(b) == Ordering::Equal, a == b);
+            assert_eq!(a.cmp(b), b.cmp(a).reverse());
+        }
+    }
+
+    // Sorting gives the same order no matter how the boards started.
+    let mut sorted = boards.clone();
+    sorted.sort();
+    boards.reverse();
+    boards.sort();
+    assert_eq!(sorted, boards);
+}
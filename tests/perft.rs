@@ -1,4 +1,10 @@
-use rchess::{ChessBoard, MoveGen};
+mod common;
+
+use common::walk;
+use rchess::{
+    ChessBoard, FenFormatError, FenLoadError, Move, MoveGen, PerftStats, PerftSuiteError, PieceType,
+};
+use std::collections::HashMap;
 
 #[test]
 fn startpos() {
@@ -57,3 +63,325 @@ fn p6() {
     let nodes = MoveGen::perft(board, 5);
     assert_eq!(nodes, 164_075_551);
 }
+
+#[test]
+fn counts_match_moves() {
+    for fen in [
+        "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq -",
+        "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq -",
+        "8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - -",
+        "r3k2r/Pppp1ppp/1b3nbN/nP6/BBP1P3/q4N2/Pp1P2PP/R2Q1RK1 w kq -",
+        "rnbq1k1r/pp1Pbppp/2p5/8/2B5/8/PPP1NnPP/RNBQK2R w KQ -",
+    ] {
+        let board = ChessBoard::from_fen(fen).unwrap();
+        for mv in MoveGen::legal(&board) {
+            let child = board.get_child(mv);
+            for mv in MoveGen::legal(&child) {
+                let grandchild = child.get_child(mv);
+                assert_eq!(
+                    MoveGen::count_legal_moves(&grandchild) as usize,
+                    MoveGen::legal(&grandchild).to_vec().len()
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn detailed() {
+    let board =
+        ChessBoard::from_fen("r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq -")
+            .unwrap();
+    let stats = MoveGen::perft_detailed(board, 3);
+    assert_eq!(
+        stats,
+        PerftStats {
+            nodes: 97_862,
+            captures: 17_102,
+            en_passants: 45,
+            castles: 3_162,
+            promotions: 0,
+            checks: 993,
+            checkmates: 1,
+        }
+    );
+
+    let board = ChessBoard::from_fen("8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - -").unwrap();
+    let stats = MoveGen::perft_detailed(board, 5);
+    assert_eq!(
+        stats,
+        PerftStats {
+            nodes: 674_624,
+            captures: 52_051,
+            en_passants: 1_165,
+            castles: 0,
+            promotions: 0,
+            checks: 52_950,
+            checkmates: 0,
+        }
+    );
+
+    let board =
+        ChessBoard::from_fen("r3k2r/Pppp1ppp/1b3nbN/nP6/BBP1P3/q4N2/Pp1P2PP/R2Q1RK1 w kq -")
+            .unwrap();
+    let stats = MoveGen::perft_detailed(board.clone(), 3);
+    assert_eq!(
+        stats,
+        PerftStats {
+            nodes: 9_467,
+            captures: 1_021,
+            en_passants: 4,
+            castles: 0,
+            promotions: 120,
+            checks: 38,
+            checkmates: 22,
+        }
+    );
+    assert_eq!(stats.nodes, u64::from(MoveGen::perft(board, 3)));
+}
+
+#[test]
+fn has_legal_move() {
+    // Walk the tree and compare against counting the moves.
+    fn check(board: &ChessBoard) {
+        assert_eq!(
+            MoveGen::has_legal_move(board),
+            MoveGen::count_legal_moves(board) > 0,
+            "{}",
+            board.get_fen()
+        );
+    }
+
+    for fen in [
+        "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq -",
+        "8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - -",
+        "r3k2r/Pppp1ppp/1b3nbN/nP6/BBP1P3/q4N2/Pp1P2PP/R2Q1RK1 w kq -",
+        "1r5k/8/8/8/8/8/7r/K7 w - -",
+        "6k1/5ppp/8/8/8/8/5PPP/r5K1 w - -",
+    ] {
+        let board = ChessBoard::from_fen(fen).unwrap();
+        check(&board);
+        walk(&board, 3, &mut |board, mv| check(&board.get_child(mv)));
+    }
+}
+
+#[test]
+fn pseudo_legal() {
+    // Filtering out the moves that leave the king in check gives the legal moves.
+    fn check(board: &ChessBoard) {
+        let us = board.turn();
+        let mut filtered = MoveGen::pseudo_legal(board)
+            .into_iter()
+            .filter(|&mv| {
+                let child = board.get_child(mv);
+                !child.is_attacked(child.get_king_square(us), child.turn())
+            })
+            .collect::<Vec<_>>();
+        let mut legal = MoveGen::legal(board).to_vec();
+        filtered.sort_by_key(|mv| mv.to_u16());
+        legal.sort_by_key(|mv| mv.to_u16());
+        assert_eq!(filtered, legal, "{}", board.get_fen());
+    }
+
+    for fen in [
+        "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq -",
+        "8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - -",
+        "r3k2r/Pppp1ppp/1b3nbN/nP6/BBP1P3/q4N2/Pp1P2PP/R2Q1RK1 w kq -",
+        "4k3/8/8/8/1b6/8/3N4/4K3 w - -",
+    ] {
+        let board = ChessBoard::from_fen(fen).unwrap();
+        check(&board);
+        walk(&board, 2, &mut |board, mv| check(&board.get_child(mv)));
+    }
+}
+
+#[test]
+fn no_underpromotions() {
+    for fen in [
+        "r3k2r/Pppp1ppp/1b3nbN/nP6/BBP1P3/q4N2/Pp1P2PP/R2Q1RK1 w kq -",
+        "rnbq1k1r/pp1Pbppp/2p5/8/2B5/8/PPP1NnPP/RNBQK2R w KQ -",
+        "n1n5/PPPk4/8/8/8/8/4Kppp/5N1N b - -",
+        "n1n5/PPPk4/8/8/8/8/4Kppp/5N1N w - -",
+    ] {
+        let board = ChessBoard::from_fen(fen).unwrap();
+        let expected = MoveGen::legal(&board)
+            .filter(|mv| {
+                !matches!(
+                    mv,
+                    Move::Promote {
+                        target: PieceType::Bishop | PieceType::Rook,
+                        ..
+                    } | Move::PromoteCapture {
+                        target: PieceType::Bishop | PieceType::Rook,
+                        ..
+                    }
+                )
+            })
+            .collect::<Vec<_>>();
+
+        // The remaining length is exact at every step, including inside a promotion sequence.
+        let mut moves = MoveGen::legal_no_underpromotions(&board);
+        let mut generated = Vec::new();
+        for remaining in (0..moves.len()).rev() {
+            generated.push(moves.next().unwrap());
+            assert_eq!(moves.len(), remaining);
+        }
+        assert_eq!(moves.next(), None);
+        assert_eq!(generated, expected);
+
+        let mut moves = MoveGen::legal(&board);
+        for remaining in (0..moves.len()).rev() {
+            moves.next().unwrap();
+            assert_eq!(moves.len(), remaining);
+        }
+    }
+}
+
+#[test]
+fn suite() {
+    let results = MoveGen::perft_suite(&[
+        (
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq -",
+            &[(1, 20), (2, 400), (3, 8_902), (4, 197_281)],
+        ),
+        (
+            "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq -",
+            &[(1, 48), (2, 2_039), (3, 97_862)],
+        ),
+        (
+            "8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - -",
+            &[(1, 14), (2, 191), (3, 2_812), (4, 43_238)],
+        ),
+        (
+            "r3k2r/Pppp1ppp/1b3nbN/nP6/BBP1P3/q4N2/Pp1P2PP/R2Q1RK1 w kq -",
+            &[(1, 6), (2, 264), (3, 9_467)],
+        ),
+        (
+            "rnbq1k1r/pp1Pbppp/2p5/8/2B5/8/PPP1NnPP/RNBQK2R w KQ -",
+            &[(1, 44), (2, 1_486), (3, 62_379)],
+        ),
+        // A wrong expected count.
+        (
+            "rnbq1k1r/pp1Pbppp/2p5/8/2B5/8/PPP1NnPP/RNBQK2R w KQ -",
+            &[(1, 44), (2, 1_487), (3, 62_379)],
+        ),
+        // A malformed fen.
+        (
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR x KQkq -",
+            &[(1, 20)],
+        ),
+    ]);
+
+    assert!(results[..5].iter().all(|result| result.is_ok()));
+
+    let Err(PerftSuiteError::Mismatch(mismatch)) = &results[5] else {
+        panic!("expected a mismatch, got {:?}", results[5]);
+    };
+    assert_eq!(mismatch.depth, 2);
+    assert_eq!(mismatch.expected, 1_487);
+    assert_eq!(mismatch.actual, 1_486);
+    assert_eq!(mismatch.divide.len(), 44);
+    assert_eq!(
+        mismatch.divide.iter().map(|(_, nodes)| nodes).sum::<u64>(),
+        mismatch.actual
+    );
+    assert_eq!(
+        mismatch.to_string(),
+        "perft of 'rnbq1k1r/pp1Pbppp/2p5/8/2B5/8/PPP1NnPP/RNBQK2R w KQ -' to depth 2 found 1486 \
+         nodes, expected 1487"
+    );
+
+    assert_eq!(
+        results[6],
+        Err(PerftSuiteError::InvalidFen {
+            index: 6,
+            error: FenLoadError::Formatting(FenFormatError::InvalidTurnSection)
+        })
+    );
+    assert_eq!(
+        results[6].as_ref().unwrap_err().to_string(),
+        "entry 7 has a fen that could not be loaded: there was an error with the fen formatting"
+    );
+}
+
+#[test]
+fn into_sorted_by() {
+    let board =
+        ChessBoard::from_fen("r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq -")
+            .unwrap();
+    let moves = MoveGen::legal(&board).into_sorted_by(|mv| mv.is_capture() as i32);
+    assert_eq!(moves.len(), 48);
+
+    // Captures come first, and ties keep the generation order.
+    let captures = moves.iter().take_while(|mv| mv.is_capture()).count();
+    assert_eq!(captures, 8);
+    assert!(moves[captures..].iter().all(|mv| !mv.is_capture()));
+    let legal = MoveGen::legal(&board).collect::<Vec<_>>();
+    assert_eq!(
+        moves[..captures].to_vec(),
+        legal
+            .iter()
+            .copied()
+            .filter(|mv| mv.is_capture())
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn random_legal() {
+    let mut rng = fastrand::Rng::with_seed(3);
+
+    // Every start position move is picked about 1 in 20 times.
+    let board = ChessBoard::new();
+    let mut counts = HashMap::new();
+    for _ in 0..20_000 {
+        let mv = MoveGen::random_legal(&board, &mut rng).unwrap();
+        *counts.entry(mv).or_insert(0) += 1;
+    }
+    assert_eq!(counts.len(), 20);
+    assert!(counts.values().all(|&count| (850..1150).contains(&count)));
+
+    // Each promotion is its own move: four promotions and three king moves.
+    let board = ChessBoard::from_fen("8/4P3/8/8/8/8/8/k1K5 w - -").unwrap();
+    let mut counts = HashMap::new();
+    for _ in 0..7_000 {
+        let mv = MoveGen::random_legal(&board, &mut rng).unwrap();
+        *counts.entry(mv).or_insert(0) += 1;
+    }
+    assert_eq!(counts.len(), 7);
+    assert!(counts.values().all(|&count| (850..1150).contains(&count)));
+
+    // No move is picked when there are none.
+    let board = ChessBoard::from_fen("1r5k/8/8/8/8/8/7r/K7 w - -").unwrap();
+    assert_eq!(MoveGen::random_legal(&board, &mut rng), None);
+}
+
+#[test]
+fn fill() {
+    // The position with the most legal moves.
+    let board = ChessBoard::from_fen("R6R/3Q4/1Q4Q1/4Q3/2Q4Q/Q4Q2/pp1Q4/kBNN1KB1 w - -").unwrap();
+    assert_eq!(board.validate(), Ok(()));
+    let mut buffer = [Move::PLACEHOLDER; MoveGen::MAX_LEGAL_MOVES];
+    let len = MoveGen::legal(&board).fill(&mut buffer);
+    assert_eq!(len, MoveGen::MAX_LEGAL_MOVES);
+    assert_eq!(buffer.to_vec(), MoveGen::legal(&board).to_vec());
+
+    // Positions that do not validate can have more moves.
+    let board = ChessBoard::from_fen("QQ5R/3Q4/1Q4Q1/4Q3/2Q4Q/Q4Q2/pp1Q4/kBNN1KB1 w - -").unwrap();
+    assert!(board.validate().is_err());
+    let mut moves = MoveGen::legal(&board);
+    assert_eq!(moves.fill(&mut buffer), MoveGen::MAX_LEGAL_MOVES);
+    assert_eq!(moves.len(), 4);
+
+    // The moves that do not fit are kept.
+    let board = ChessBoard::new();
+    let mut buffer = [Move::PLACEHOLDER; 16];
+    let mut moves = MoveGen::legal(&board);
+    assert_eq!(moves.fill(&mut buffer), 16);
+    assert_eq!(moves.len(), 4);
+    let rest = moves.to_vec();
+    assert_eq!(
+        [buffer.to_vec(), rest].concat(),
+        MoveGen::legal(&board).to_vec()
+    );
+}
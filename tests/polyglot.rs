@@ -0,0 +1,152 @@
+use rchess::{CastleSide, ChessBoard, Move, MoveGen, PolyglotBook, Square};
+
+/// Encodes a Polyglot book entry.
+fn entry(key: u64, mv: u16, weight: u16) -> Vec<u8> {
+    let mut bytes = key.to_be_bytes().to_vec();
+    bytes.extend(mv.to_be_bytes());
+    bytes.extend(weight.to_be_bytes());
+    bytes.extend(0u32.to_be_bytes());
+    bytes
+}
+
+/// Encodes a Polyglot move.
+fn polyglot_move(start: Square, end: Square, promotion: u16) -> u16 {
+    end.file() as u16
+        | (end.rank() as u16) << 3
+        | (start.file() as u16) << 6
+        | (start.rank() as u16) << 9
+        | promotion << 12
+}
+
+#[test]
+fn castling() {
+    let board = ChessBoard::from_fen("r3k2r/8/8/8/8/8/8/R3K2R w KQkq -").unwrap();
+    let mut bytes = entry(7, polyglot_move(Square::E1, Square::H1, 0), 3);
+    bytes.extend(entry(7, polyglot_move(Square::E1, Square::A1, 0), 2));
+    let book = PolyglotBook::from_bytes(&bytes).unwrap();
+
+    assert_eq!(
+        book.lookup_key(7, &board),
+        vec![
+            (
+                Move::Castle {
+                    start: Square::E1,
+                    end: Square::G1,
+                    side: CastleSide::Kingside
+                },
+                3
+            ),
+            (
+                Move::Castle {
+                    start: Square::E1,
+                    end: Square::C1,
+                    side: CastleSide::Queenside
+                },
+                2
+            ),
+        ]
+    );
+}
+
+#[test]
+fn promotions() {
+    let board = ChessBoard::from_fen("k7/3P4/8/8/8/8/8/K7 w - -").unwrap();
+    let mut bytes = entry(1, polyglot_move(Square::D7, Square::D8, 1), 5);
+
+    // A promotion without a promotion piece is invalid.
+    bytes.extend(entry(1, polyglot_move(Square::D7, Square::D8, 0), 5));
+    let book = PolyglotBook::from_bytes(&bytes).unwrap();
+
+    let d7d8n = MoveGen::create_str_move(&board, "d7d8n").unwrap();
+    assert_eq!(book.lookup_key(1, &board), vec![(d7d8n, 5)]);
+}
+
+#[test]
+fn unsorted_keys() {
+    let board = ChessBoard::new();
+    let e2e4 = polyglot_move(Square::E2, Square::E4, 0);
+    let d2d4 = polyglot_move(Square::D2, Square::D4, 0);
+    let mut bytes = entry(9, e2e4, 1);
+    bytes.extend(entry(3, d2d4, 2));
+    bytes.extend(entry(9, d2d4, 4));
+    let book = PolyglotBook::from_bytes(&bytes).unwrap();
+    assert_eq!(book.len(), 3);
+
+    let moves = book.lookup_key(9, &board);
+    assert_eq!(moves.len(), 2);
+    assert_eq!(book.lookup_key(3, &board).len(), 1);
+    assert!(book.lookup_key(5, &board).is_empty());
+}
+
+#[test]
+fn illegal_moves() {
+    let board = ChessBoard::new();
+    let bytes = entry(1, polyglot_move(Square::E2, Square::E5, 0), 1);
+    let book = PolyglotBook::from_bytes(&bytes).unwrap();
+    assert!(book.lookup_key(1, &board).is_empty());
+}
+
+#[test]
+fn polyglot_key() {
+    // The reference keys from the Polyglot book format specification.
+    for (moves, key) in [
+        (&[][..], 0x463B96181691FC9C),
+        (&["e2e4"][..], 0x823C9B50FD114196),
+        (&["e2e4", "d7d5"][..], 0x0756B94461C50FB0),
+        (&["e2e4", "d7d5", "e4e5"][..], 0x662FAFB965DB29D4),
+        (&["e2e4", "d7d5", "e4e5", "f7f5"][..], 0x22A48B5A8E47FF78),
+        (
+            &["e2e4", "d7d5", "e4e5", "f7f5", "e1e2"][..],
+            0x652A607CA3F242C1,
+        ),
+        (
+            &["e2e4", "d7d5", "e4e5", "f7f5", "e1e2", "e8f7"][..],
+            0x00FDD303C946BDD9,
+        ),
+        (
+            &["a2a4", "b7b5", "h2h4", "b5b4", "c2c4"][..],
+            0x3C8123EA7B067637,
+        ),
+        (
+            &["a2a4", "b7b5", "h2h4", "b5b4", "c2c4", "b4c3", "a1a3"][..],
+            0x5C3F9B829B279560,
+        ),
+    ] {
+        let board = ChessBoard::from_str_moves(moves).unwrap();
+        assert_eq!(board.polyglot_key(), key, "{:?}", moves);
+    }
+}
+
+#[test]
+fn lookup() {
+    // A book with replies to 1. e4 for both sides.
+    let start = ChessBoard::new();
+    let after_e4 = ChessBoard::from_str_moves(&["e2e4"]).unwrap();
+    let mut bytes = entry(
+        start.polyglot_key(),
+        polyglot_move(Square::E2, Square::E4, 0),
+        8,
+    );
+    bytes.extend(entry(
+        after_e4.polyglot_key(),
+        polyglot_move(Square::C7, Square::C5, 0),
+        6,
+    ));
+    bytes.extend(entry(
+        after_e4.polyglot_key(),
+        polyglot_move(Square::E7, Square::E5, 0),
+        4,
+    ));
+    let book = PolyglotBook::from_bytes(&bytes).unwrap();
+
+    let e2e4 = MoveGen::create_str_move(&start, "e2e4").unwrap();
+    assert_eq!(book.lookup(&start), vec![(e2e4, 8)]);
+
+    let c7c5 = MoveGen::create_str_move(&after_e4, "c7c5").unwrap();
+    let e7e5 = MoveGen::create_str_move(&after_e4, "e7e5").unwrap();
+    assert_eq!(book.lookup(&after_e4), vec![(c7c5, 6), (e7e5, 4)]);
+
+    // Positions missing from the book have no moves.
+    let board = ChessBoard::from_str_moves(&["d2d4"]).unwrap();
+    assert!(book.lookup(&board).is_empty());
+}
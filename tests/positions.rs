@@ -1,4 +1,11 @@
-use rchess::{BoardBuilder, ChessBoard, Piece, Square};
+mod common;
+
+use common::random_bitboards;
+use rchess::{
+    BitBoard, BoardBuilder, BoardBuilderError, CastleSide, ChessBoard, Color, DrawReason,
+    GameResult, IllegalMoveError, Imbalance, Move, MoveCreationError, MoveGen, Piece, PieceType,
+    PieceValues, PositionError, Rank, Square, StrMoveCreationError, StrMovesError,
+};
 
 #[test]
 fn start_pos() {
@@ -100,7 +107,18 @@ fn pawn_on_last_b() {
 fn from_moves_err() {
     let board =
         ChessBoard::from_str_moves(&["e2e3", "d7d6", "e1e2", "c8g4", "g1f3", "g4h5", "f3e1"]);
-    assert!(board.is_err());
+    assert_eq!(
+        board,
+        Err(StrMovesError {
+            index: 6,
+            input: "f3e1",
+            error: StrMoveCreationError::IllegalMove(MoveCreationError),
+        })
+    );
+
+    let err = ChessBoard::from_str_moves(&["e2e4", "e7e5", "xyz"]).unwrap_err();
+    assert_eq!((err.index, err.input), (2, "xyz"));
+    assert_eq!(err.error, StrMoveCreationError::InvalidMove);
 }
 
 #[test]
@@ -111,6 +129,1096 @@ fn halfmoves() {
 
 #[test]
 fn invalid_halfmoves() {
-    let board = ChessBoard::from_fen("7k/8/1r6/8/8/6R1/8/K7 w - - 101");
+    let board = ChessBoard::from_fen("7k/8/1r6/8/8/6R1/8/K7 w - - x");
+    assert!(board.is_err());
+    let board = ChessBoard::from_fen("7k/8/1r6/8/8/6R1/8/K7 w - - -1");
+    assert!(board.is_err());
+}
+
+#[test]
+fn halfmoves_past_limits() {
+    for (halfmoves, reason) in [
+        (100, DrawReason::FiftyMoves),
+        (101, DrawReason::FiftyMoves),
+        (150, DrawReason::SeventyFiveMoves),
+        (151, DrawReason::SeventyFiveMoves),
+    ] {
+        let fen = format!("7k/8/1r6/8/8/6R1/8/K7 w - - {halfmoves}");
+        let board = ChessBoard::from_fen(&fen).unwrap();
+        assert_eq!(board.halfmoves(), halfmoves);
+        assert_eq!(board.outcome(), Some(GameResult::Draw { reason }));
+    }
+
+    // Clocks too large for the board saturate, however large they are.
+    for halfmoves in ["256", "1000", "4294967296", "99999999999999999999999999"] {
+        let fen = format!("7k/8/1r6/8/8/6R1/8/K7 w - - {halfmoves}");
+        let board = ChessBoard::from_fen(&fen).unwrap();
+        assert_eq!(board.halfmoves(), u8::MAX);
+    }
+}
+
+#[test]
+fn many_pieces() {
+    let board = ChessBoard::from_fen("NNNNNNNN/NNNNNNNN/N7/8/8/8/8/K6k w - -").unwrap();
+    let n_moves = MoveGen::count_legal_moves(&board);
+    assert_eq!(n_moves, 43);
+    assert_eq!(MoveGen::legal(&board).to_vec().len(), n_moves as usize);
+}
+
+#[test]
+fn too_many_pieces() {
+    let board = ChessBoard::from_fen("NNNNNNNN/NNNNNNNN/NN6/8/8/8/8/K6k w - -");
     assert!(board.is_err());
 }
+
+#[test]
+fn piece_move_counts() {
+    let board =
+        ChessBoard::from_fen("r3k2r/Pppp1ppp/1b3nbN/nP6/BBP1P3/q4N2/Pp1P2PP/R2Q1RK1 w kq -")
+            .unwrap();
+    let total: u32 = rchess::SQUARES
+        .iter()
+        .map(|sq| MoveGen::piece_move_count(&board, *sq))
+        .sum();
+    assert_eq!(total, MoveGen::count_legal_moves(&board));
+}
+
+#[test]
+fn validate_pawn_on_first() {
+    let board = ChessBoard::from_fen("4k3/8/8/8/8/8/8/P3K3 w - -").unwrap();
+    assert_eq!(board.validate(), Err(PositionError::PawnOnBackRank));
+}
+
+#[test]
+fn validate_too_many_pawns() {
+    let board = ChessBoard::from_fen("4k3/8/8/8/8/p7/pppppppp/4K3 w - -").unwrap();
+    assert_eq!(board.validate(), Err(PositionError::TooManyPawns));
+}
+
+#[test]
+fn validate_promoted_pieces() {
+    // Two dark squared bishops need a promotion.
+    let board = ChessBoard::from_fen("4k3/8/8/8/8/8/PPPPPPPP/2B1K1B1 w - -").unwrap();
+    assert_eq!(board.validate(), Err(PositionError::TooManyPromotedPieces));
+
+    // Bishops on opposite colors are fine.
+    let board = ChessBoard::from_fen("4k3/8/8/8/8/8/PPPPPPPP/2B1KB2 w - -").unwrap();
+    assert_eq!(board.validate(), Ok(()));
+
+    // One missing pawn allows one extra queen.
+    let board = ChessBoard::from_fen("4k3/8/8/8/8/8/PPPPPPP1/QQ2K3 w - -").unwrap();
+    assert_eq!(board.validate(), Ok(()));
+}
+
+#[test]
+fn validate_after_moves() {
+    let board = ChessBoard::from_str_moves(&["e2e4", "d7d5", "e4e5", "f7f5"]).unwrap();
+    assert_eq!(board.validate(), Ok(()));
+}
+
+#[test]
+fn packed_moves() {
+    for fen in [
+        "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq -",
+        "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq -",
+        "8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - -",
+        "r3k2r/Pppp1ppp/1b3nbN/nP6/BBP1P3/q4N2/Pp1P2PP/R2Q1RK1 w kq -",
+        "rnbqkbnr/ppp1p1pp/8/3pPp2/8/8/PPPP1PPP/RNBQKBNR w KQkq f6",
+        "n1n5/PPPk4/8/8/8/8/4Kppp/5N1N b - -",
+    ] {
+        let board = ChessBoard::from_fen(fen).unwrap();
+        let moves = MoveGen::legal(&board).to_vec();
+        for mv in &moves {
+            assert_eq!(Move::from_u16(&board, mv.to_u16()), Some(*mv));
+        }
+
+        // Every move packs to a different value.
+        let mut packed: Vec<u16> = moves.iter().map(|mv| mv.to_u16()).collect();
+        packed.sort();
+        packed.dedup();
+        assert_eq!(packed.len(), moves.len());
+    }
+}
+
+#[test]
+fn to_array() {
+    let board =
+        ChessBoard::from_fen("r3k2r/Pppp1ppp/1b3nbN/nP6/BBP1P3/q4N2/Pp1P2PP/R2Q1RK1 w kq -")
+            .unwrap();
+    let pieces = board.to_array();
+    for sq in rchess::SQUARES {
+        assert_eq!(pieces[sq.index()], board.piece_at(sq));
+    }
+}
+
+#[test]
+fn from_array() {
+    let board = ChessBoard::new();
+    let built = BoardBuilder::from_array(board.to_array())
+        .unwrap()
+        .turn(Color::White)
+        .unwrap()
+        .castle_right(CastleSide::Kingside, Color::White)
+        .unwrap()
+        .castle_right(CastleSide::Queenside, Color::White)
+        .unwrap()
+        .castle_right(CastleSide::Kingside, Color::Black)
+        .unwrap()
+        .castle_right(CastleSide::Queenside, Color::Black)
+        .unwrap()
+        .finish()
+        .unwrap();
+    assert_eq!(built, board);
+    assert_eq!(built.hash(), board.hash());
+}
+
+#[test]
+fn from_array_two_kings() {
+    let mut pieces = [None; 64];
+    pieces[Square::A1.index()] = Some(Piece::WHITE_KING);
+    pieces[Square::H1.index()] = Some(Piece::WHITE_KING);
+    assert_eq!(
+        BoardBuilder::from_array(pieces),
+        Err(BoardBuilderError::TwoKings)
+    );
+}
+
+#[test]
+fn start_pos_attacks() {
+    let board = ChessBoard::new();
+    let expected = BitBoard::from_rank(Rank::Second)
+        | BitBoard::from_rank(Rank::Third)
+        | (BitBoard::from_rank(Rank::First) & !BitBoard::from_squares(&[Square::A1, Square::H1]));
+    assert_eq!(board.attacks_by(Color::White), expected);
+    assert_eq!(board.attacks_by(Color::White).popcnt(), 22);
+}
+
+#[test]
+fn king_flight_squares() {
+    // The king cannot hide behind itself from the rook.
+    let board = ChessBoard::from_fen("8/8/8/8/8/8/k7/r3K3 w - -").unwrap();
+    assert_eq!(
+        board.king_flight_squares(Color::White),
+        BitBoard::from_squares(&[Square::D2, Square::E2, Square::F2])
+    );
+
+    // Squares next to the enemy king are unsafe.
+    let board = ChessBoard::from_fen("8/8/8/8/8/3k4/8/3K4 w - -").unwrap();
+    assert_eq!(
+        board.king_flight_squares(Color::White),
+        BitBoard::from_squares(&[Square::C1, Square::E1])
+    );
+}
+
+#[test]
+fn smallest_attacker() {
+    let board = ChessBoard::from_fen("3rk3/8/2n5/4p3/3R4/2B5/8/3QK3 b - -").unwrap();
+    assert_eq!(
+        board.smallest_attacker(Square::D4, Color::Black),
+        Some(Square::E5)
+    );
+    assert_eq!(
+        board.smallest_attacker(Square::D4, Color::White),
+        Some(Square::C3)
+    );
+    assert_eq!(
+        board.smallest_attacker(Square::D8, Color::White),
+        Some(Square::D4)
+    );
+
+    // The rook on d4 blocks the bishop from e5.
+    assert_eq!(board.smallest_attacker(Square::E5, Color::White), None);
+}
+
+#[test]
+fn halfmove_saturation() {
+    // The blocked pawns keep enough material on the board for the clock to decide the game.
+    let mut board = ChessBoard::from_fen("7k/p7/P7/8/8/8/8/KN6 w - - 99").unwrap();
+    for mv in ["b1c3", "h8g8", "c3b1", "g8h8"].iter().cycle().take(256) {
+        board.make_move(MoveGen::create_str_move(&board, mv).unwrap());
+    }
+    assert_eq!(board.halfmoves(), u8::MAX);
+
+    // A saturated clock still draws.
+    assert!(board.can_claim_fifty_move());
+    assert_eq!(
+        board.outcome(),
+        Some(GameResult::Draw {
+            reason: DrawReason::SeventyFiveMoves
+        })
+    );
+}
+
+#[test]
+fn fifty_move_boundary() {
+    let board = ChessBoard::from_fen("7k/8/8/8/8/8/8/KR6 w - - 98").unwrap();
+    assert!(!board.can_claim_fifty_move());
+
+    let board = ChessBoard::from_fen("7k/8/8/8/8/8/8/KR6 w - - 99").unwrap();
+    assert!(!board.can_claim_fifty_move());
+
+    let mut board = ChessBoard::from_fen("7k/8/8/8/8/8/8/KR6 w - - 100").unwrap();
+    assert!(board.can_claim_fifty_move());
+
+    board.make_move(MoveGen::create_str_move(&board, "b1b2").unwrap());
+    assert_eq!(board.halfmoves(), 101);
+    assert!(board.can_claim_fifty_move());
+
+    // A pawn move resets the clock.
+    let mut board = ChessBoard::from_fen("7k/8/8/8/8/8/P7/KR6 w - - 100").unwrap();
+    board.make_move(MoveGen::create_str_move(&board, "a2a3").unwrap());
+    assert!(!board.can_claim_fifty_move());
+}
+
+#[test]
+fn movegen_clone() {
+    // Promotions are yielded over several calls, so clone in the middle of them too.
+    let board =
+        ChessBoard::from_fen("r2q1rk1/pP1p2pp/Q4n2/bbp1p3/Np6/1B3NBn/pPPP1PPP/R3K2R b KQ -")
+            .unwrap();
+    let all = MoveGen::legal(&board).to_vec();
+    for taken in 0..=all.len() {
+        let mut moves = MoveGen::legal(&board);
+        for _ in 0..taken {
+            moves.next();
+        }
+        let snapshot = moves.clone();
+        assert_eq!(snapshot.count_moves(), moves.count_moves());
+        assert_eq!(snapshot.collect::<Vec<_>>(), all[taken..]);
+    }
+}
+
+#[test]
+fn staged_moves() {
+    for fen in [
+        "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq -",
+        "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq -",
+        "r3k2r/Pppp1ppp/1b3nbN/nP6/BBP1P3/q4N2/Pp1P2PP/R2Q1RK1 w kq -",
+        "rnbqkbnr/ppp1p1pp/8/3pPp2/8/8/PPPP1PPP/RNBQKBNR w KQkq f6",
+        "n1n5/PPPk4/8/8/8/8/4Kppp/5N1N b - -",
+        "4k3/8/8/8/8/8/4q3/4K3 w - -",
+    ] {
+        let board = ChessBoard::from_fen(fen).unwrap();
+        let captures = MoveGen::captures_only(&board).to_vec();
+        let staged = MoveGen::staged(&board).to_vec();
+
+        // The captures come first.
+        assert_eq!(staged[..captures.len()], captures);
+
+        // Every legal move is yielded exactly once.
+        let mut legal = MoveGen::legal(&board).to_vec();
+        let mut sorted = staged.clone();
+        legal.sort_by_key(|mv| mv.to_u16());
+        sorted.sort_by_key(|mv| mv.to_u16());
+        assert_eq!(sorted, legal);
+        assert_eq!(
+            MoveGen::staged(&board).count_moves(),
+            MoveGen::count_legal_moves(&board)
+        );
+    }
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "expected Piece")]
+fn make_move_missing_piece() {
+    let mut board = ChessBoard::new();
+    board.make_move(Move::Quiet {
+        start: Square::E4,
+        end: Square::E5,
+        moving: PieceType::Pawn,
+    });
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "occupied square e7")]
+fn make_move_occupied_end() {
+    let mut board = ChessBoard::new();
+    board.make_move(Move::Quiet {
+        start: Square::E2,
+        end: Square::E7,
+        moving: PieceType::Pawn,
+    });
+}
+
+#[test]
+fn try_make_move() {
+    // The knight on d2 is pinned by the bishop on b4.
+    let mut board = ChessBoard::from_fen("4k3/8/8/8/1b6/8/3N4/4K3 w - - 0 1").unwrap();
+    let pinned = Move::Quiet {
+        start: Square::D2,
+        end: Square::F3,
+        moving: PieceType::Knight,
+    };
+    assert_eq!(board.try_make_move(pinned), Err(IllegalMoveError(pinned)));
+    assert_eq!(
+        board,
+        ChessBoard::from_fen("4k3/8/8/8/1b6/8/3N4/4K3 w - - 0 1").unwrap()
+    );
+
+    // A move with the wrong variant is rejected.
+    let capture = Move::Capture {
+        start: Square::E1,
+        end: Square::E2,
+        moving: PieceType::King,
+    };
+    assert_eq!(board.try_make_move(capture), Err(IllegalMoveError(capture)));
+
+    let legal = MoveGen::create_str_move(&board, "e1e2").unwrap();
+    assert_eq!(board.try_make_move(legal), Ok(()));
+    assert_eq!(board.turn(), Color::Black);
+}
+
+#[test]
+fn filter_legal() {
+    let board = ChessBoard::from_fen("1r5k/P7/8/8/8/8/8/K7 w - - 0 1").unwrap();
+    let legal = MoveGen::legal(&board).to_vec();
+    assert_eq!(
+        MoveGen::filter_legal(&board, &legal),
+        vec![true; legal.len()]
+    );
+
+    let candidates = [
+        Move::PromoteCapture {
+            start: Square::A7,
+            end: Square::B8,
+            target: PieceType::Rook,
+        },
+        Move::Promote {
+            start: Square::A7,
+            end: Square::A8,
+            target: PieceType::Knight,
+        },
+        Move::Promote {
+            start: Square::A7,
+            end: Square::A8,
+            target: PieceType::King,
+        },
+        Move::Capture {
+            start: Square::A7,
+            end: Square::B8,
+            moving: PieceType::Pawn,
+        },
+        Move::Quiet {
+            start: Square::B8,
+            end: Square::B1,
+            moving: PieceType::Rook,
+        },
+    ];
+    assert_eq!(
+        MoveGen::filter_legal(&board, &candidates),
+        vec![true, true, false, false, false]
+    );
+}
+
+#[test]
+fn position_eq() {
+    // The en passant square on e3 cannot be captured.
+    let pushed = ChessBoard::from_str_moves(&["e2e4"]).unwrap();
+    let transposed = ChessBoard::from_str_moves(&["e2e4", "g8f6", "g1f3", "f6g8", "f3g1"]).unwrap();
+    assert_ne!(pushed, transposed);
+    assert!(pushed.position_eq(&transposed));
+    assert!(transposed.position_eq(&pushed));
+
+    // The en passant square on d6 can be captured.
+    let pushed = ChessBoard::from_str_moves(&["e2e4", "a7a6", "e4e5", "d7d5"]).unwrap();
+    let fen =
+        ChessBoard::from_fen("rnbqkbnr/1pp1pppp/p7/3pP3/8/8/PPPP1PPP/RNBQKBNR w KQkq -").unwrap();
+    assert!(!pushed.position_eq(&fen));
+
+    // Positions that differ in more than the en passant square are still unequal.
+    assert!(!ChessBoard::new().position_eq(&transposed));
+}
+
+#[test]
+fn quiet_checks() {
+    for fen in [
+        "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq -",
+        "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq -",
+        "8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - -",
+        "r3k2r/Pppp1ppp/1b3nbN/nP6/BBP1P3/q4N2/Pp1P2PP/R2Q1RK1 w kq -",
+        "rnbq1k1r/pp1Pbppp/2p5/8/2B5/8/PPP1NnPP/RNBQK2R w KQ -",
+        "5k2/8/8/8/8/8/8/4K2R w K -",
+        "8/8/8/8/k2Pp2Q/8/8/3K4 b - d3",
+        "4k3/1P6/8/8/8/8/3N4/B2QK3 w - -",
+        "4k3/8/8/8/4N3/8/1B6/4RK2 w - -",
+    ] {
+        let board = ChessBoard::from_fen(fen).unwrap();
+
+        // Compare against making every move.
+        let mut expected = MoveGen::legal(&board)
+            .filter(|&mv| {
+                !matches!(
+                    mv,
+                    Move::Capture { .. } | Move::PromoteCapture { .. } | Move::EnPassant { .. }
+                )
+            })
+            .filter(|&mv| !board.get_child(mv).checkers().is_empty())
+            .collect::<Vec<_>>();
+        let mut checks = MoveGen::quiet_checks(&board);
+        expected.sort_by_key(|mv| mv.to_u16());
+        checks.sort_by_key(|mv| mv.to_u16());
+        assert_eq!(checks, expected, "{}", fen);
+
+        let mut expected = MoveGen::legal(&board)
+            .filter(|mv| mv.is_capture())
+            .filter(|&mv| !board.get_child(mv).checkers().is_empty())
+            .collect::<Vec<_>>();
+        let mut checks = MoveGen::checking_captures(&board);
+        expected.sort_by_key(|mv| mv.to_u16());
+        checks.sort_by_key(|mv| mv.to_u16());
+        assert_eq!(checks, expected, "{}", fen);
+
+        for mv in MoveGen::legal(&board) {
+            assert_eq!(
+                board.gives_check(mv),
+                !board.get_child(mv).checkers().is_empty(),
+                "{} {}",
+                fen,
+                mv
+            );
+        }
+    }
+}
+
+#[test]
+fn checkers_against() {
+    for fen in [
+        "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq -",
+        "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq -",
+        "r3k2r/Pppp1ppp/1b3nbN/nP6/BBP1P3/q4N2/Pp1P2PP/R2Q1RK1 w kq -",
+        "8/8/8/8/k2Pp2Q/8/8/3K4 b - d3",
+    ] {
+        let board = ChessBoard::from_fen(fen).unwrap();
+        assert_eq!(board.checkers_against(board.turn()), board.checkers());
+
+        // After a legal move, the mover is never in check.
+        for mv in MoveGen::legal(&board) {
+            let child = board.get_child(mv);
+            assert!(child.checkers_against(board.turn()).is_empty());
+            assert_eq!(child.checkers_against(child.turn()), child.checkers());
+        }
+    }
+
+    // A double check.
+    let board = ChessBoard::from_fen("4k3/8/5N2/8/8/8/8/4RK2 b - -").unwrap();
+    assert_eq!(
+        board.checkers_against(Color::Black),
+        BitBoard::from_squares(&[Square::E1, Square::F6])
+    );
+}
+
+#[test]
+fn is_recapture() {
+    let mut board = ChessBoard::from_fen("4k3/2p5/8/3pP3/6Nr/8/8/4K3 w - d6 0 1").unwrap();
+    let en_passant = MoveGen::create_str_move(&board, "e5d6").unwrap();
+    board.make_move(en_passant);
+
+    // Recapturing the pawn that captured en passant.
+    let mv = MoveGen::create_str_move(&board, "c7d6").unwrap();
+    assert!(board.is_recapture(mv, en_passant));
+
+    // Capturing somewhere else.
+    let mv = MoveGen::create_str_move(&board, "h4g4").unwrap();
+    assert!(!board.is_recapture(mv, en_passant));
+
+    // A move onto the square is only a recapture if the last move captured.
+    let mut board = ChessBoard::from_fen("4k3/8/2p5/8/3P4/8/8/4K3 w - - 0 1").unwrap();
+    let push = MoveGen::create_str_move(&board, "d4d5").unwrap();
+    board.make_move(push);
+    let mv = MoveGen::create_str_move(&board, "c6d5").unwrap();
+    assert!(!board.is_recapture(mv, push));
+}
+
+#[test]
+fn diff() {
+    let board = ChessBoard::new();
+    assert!(board.diff(&board).is_empty());
+
+    // En passant changes three squares.
+    let before = ChessBoard::from_fen("4k3/8/8/3pP3/8/8/8/4K3 w - d6 0 1").unwrap();
+    let mut after = before.clone();
+    after.make_move(MoveGen::create_str_move(&before, "e5d6").unwrap());
+    assert_eq!(
+        before.diff(&after),
+        vec![
+            (Square::D5, Some(Piece::BLACK_PAWN), None),
+            (Square::E5, Some(Piece::WHITE_PAWN), None),
+            (Square::D6, None, Some(Piece::WHITE_PAWN)),
+        ]
+    );
+
+    // A promotion capture replaces the captured piece.
+    let before = ChessBoard::from_fen("1r2k3/P7/8/8/8/8/8/4K3 w - - 0 1").unwrap();
+    let mut after = before.clone();
+    after.make_move(MoveGen::create_str_move(&before, "a7b8q").unwrap());
+    assert_eq!(
+        after.diff(&before),
+        vec![
+            (Square::A7, None, Some(Piece::WHITE_PAWN)),
+            (
+                Square::B8,
+                Some(Piece::WHITE_QUEEN),
+                Some(Piece::BLACK_ROOK)
+            ),
+        ]
+    );
+}
+
+#[test]
+fn from_placement() {
+    let board = ChessBoard::from_placement("r3k2r/8/8/8/8/8/8/R3K2R", Color::Black).unwrap();
+    assert_eq!(board.turn(), Color::Black);
+    for side in [CastleSide::Kingside, CastleSide::Queenside] {
+        assert!(!board.is_castle_right_set(side, Color::White));
+        assert!(!board.is_castle_right_set(side, Color::Black));
+    }
+    assert_eq!(board.en_passant_sq(), None);
+    assert_eq!(
+        board,
+        ChessBoard::from_fen("r3k2r/8/8/8/8/8/8/R3K2R b - -").unwrap()
+    );
+
+    assert!(ChessBoard::from_placement("4k3/8/8/8/8/8/8/4K2x", Color::White).is_err());
+    assert!(ChessBoard::from_placement("4k3/8/8/8/8/8/8/4K3 w", Color::White).is_err());
+
+    // The side not to move cannot be in check.
+    assert!(ChessBoard::from_placement("4k3/8/8/8/8/8/8/4K2R", Color::Black).is_ok());
+    assert!(ChessBoard::from_placement("4k3/8/8/8/8/8/8/4R2K", Color::White).is_err());
+}
+
+#[test]
+fn canonicalize() {
+    // Every symmetric version of a pawnless position has the same canonical board.
+    let fens = [
+        "8/8/8/8/8/2k5/8/K6Q w - -",
+        "8/8/8/8/8/5k2/8/Q6K w - -",
+        "K6Q/8/2k5/8/8/8/8/8 w - -",
+        "Q6K/8/5k2/8/8/8/8/8 w - -",
+    ];
+    let (canonical, _) = ChessBoard::from_fen(fens[0]).unwrap().canonicalize();
+    for fen in fens {
+        let board = ChessBoard::from_fen(fen).unwrap();
+        let (board_canonical, transform) = board.canonicalize();
+        assert_eq!(board_canonical, canonical, "{}", fen);
+
+        // The moves map back onto the original board.
+        let mut mapped = MoveGen::legal(&board_canonical)
+            .map(|mv| transform.inverse().apply_move(mv).to_u16())
+            .collect::<Vec<_>>();
+        let mut legal = MoveGen::legal(&board)
+            .map(|mv| mv.to_u16())
+            .collect::<Vec<_>>();
+        mapped.sort();
+        legal.sort();
+        assert_eq!(mapped, legal, "{}", fen);
+    }
+
+    // Positions with pawns are only mirrored across the files.
+    let a = ChessBoard::from_fen("4k3/8/8/3pP3/8/8/8/K7 w - d6 0 1").unwrap();
+    let b = ChessBoard::from_fen("3k4/8/8/3Pp3/8/8/8/7K w - e6 0 1").unwrap();
+    assert_eq!(a.canonicalize().0, b.canonicalize().0);
+    let c = ChessBoard::from_fen("k7/8/8/8/3Pp3/8/8/4K3 b - d3 0 1").unwrap();
+    assert_ne!(a.canonicalize().0, c.canonicalize().0);
+}
+
+#[test]
+fn with_turn() {
+    // The en passant square is cleared, and the half move clock is kept.
+    let board = ChessBoard::from_fen("4k3/8/8/3pP3/8/8/8/4K3 w - d6 0 1").unwrap();
+    let swapped = board.clone().with_turn(Color::Black).unwrap();
+    assert_eq!(swapped.en_passant_sq(), None);
+    assert_eq!(swapped.halfmoves(), 0);
+    assert_eq!(
+        swapped,
+        ChessBoard::from_fen("4k3/8/8/3pP3/8/8/8/4K3 b - - 0 1").unwrap()
+    );
+    assert_eq!(board.clone().with_turn(Color::White), Ok(board));
+
+    // The pins and checks are for the new side to move.
+    let board = ChessBoard::from_fen("4k3/4r3/8/8/8/8/4B3/4K2R w - - 7 1").unwrap();
+    let swapped = board.with_turn(Color::Black).unwrap();
+    assert_eq!(swapped.pinned(), BitBoard::EMPTY);
+    assert_eq!(swapped.checkers(), BitBoard::EMPTY);
+    assert_eq!(swapped.halfmoves(), 7);
+    assert_eq!(swapped.hash(), swapped.recompute_hash());
+    assert_eq!(
+        MoveGen::count_legal_moves(&swapped),
+        MoveGen::count_legal_moves(
+            &ChessBoard::from_fen("4k3/4r3/8/8/8/8/4B3/4K2R b - - 7 1").unwrap()
+        )
+    );
+}
+
+#[test]
+fn make_move_capturing() {
+    // Every move captures what was on its end square, except en passant.
+    let board =
+        ChessBoard::from_fen("r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq -")
+            .unwrap();
+    for mv in MoveGen::legal(&board) {
+        let mut child = board.clone();
+        let captured = child.make_move_capturing(mv);
+        assert_eq!(child, board.get_child(mv));
+        match mv {
+            Move::EnPassant { .. } => unreachable!(),
+            _ => assert_eq!(captured, board.piece_at(mv.end())),
+        }
+    }
+
+    // Promotion captures return the piece on the promotion square.
+    let mut board = ChessBoard::from_fen("1n2k3/P7/8/8/8/8/8/4K3 w - -").unwrap();
+    let mv = Move::PromoteCapture {
+        start: Square::A7,
+        end: Square::B8,
+        target: PieceType::Queen,
+    };
+    assert_eq!(
+        board.make_move_capturing(mv),
+        Some(Piece::new(PieceType::Knight, Color::Black))
+    );
+
+    // En passant returns the pawn beside the capturing pawn.
+    let mut board = ChessBoard::from_fen("4k3/8/8/8/3pP3/8/8/4K3 b - e3").unwrap();
+    let mv = Move::EnPassant {
+        start: Square::D4,
+        end: Square::E3,
+    };
+    assert_eq!(
+        board.make_move_capturing(mv),
+        Some(Piece::new(PieceType::Pawn, Color::White))
+    );
+    assert_eq!(board.piece_at(Square::E4), None);
+}
+
+#[test]
+fn material_with() {
+    let board = ChessBoard::from_fen("4k3/pp6/8/8/8/8/1Q6/RN2K3 w - -").unwrap();
+    assert_eq!(
+        board.material_with(&PieceValues::DEFAULT, Color::White),
+        1720
+    );
+    assert_eq!(
+        board.material_with(&PieceValues::DEFAULT, Color::Black),
+        200
+    );
+
+    let values = PieceValues {
+        pawn: 1,
+        knight: 3,
+        bishop: 3,
+        rook: 5,
+        queen: 9,
+    };
+    assert_eq!(board.material_with(&values, Color::White), 17);
+    assert_eq!(board.material_with(&values, Color::Black), 2);
+    assert_eq!(PieceValues::default(), PieceValues::DEFAULT);
+}
+
+#[test]
+fn least_and_most_valuable() {
+    let board =
+        ChessBoard::from_fen("r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq -")
+            .unwrap();
+    let value = |square: Option<Square>| square.map(|sq| board.piece_at(sq).unwrap().kind);
+
+    for squares in random_bitboards(3) {
+        let kinds = squares
+            .into_iter()
+            .filter_map(|sq| board.piece_at(sq))
+            .map(|piece| piece.kind);
+        assert_eq!(value(board.least_valuable(squares)), kinds.clone().min());
+        assert_eq!(value(board.most_valuable(squares)), kinds.max());
+    }
+
+    assert_eq!(board.least_valuable(BitBoard::EMPTY), None);
+    assert_eq!(board.most_valuable(BitBoard::EMPTY), None);
+}
+
+#[test]
+fn phase() {
+    for (fen, phase) in [
+        ("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq -", 24),
+        ("4k3/pppppppp/8/8/8/8/PPPPPPPP/4K3 w - -", 0),
+        ("3qk3/8/8/8/8/8/8/3QK3 w - -", 8),
+        (
+            "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq -",
+            24,
+        ),
+        // Promoted queens cannot push the phase past 24.
+        ("qqqqk3/8/8/8/8/8/8/QQQQK3 w - -", 24),
+    ] {
+        assert_eq!(ChessBoard::from_fen(fen).unwrap().phase(), phase);
+    }
+}
+
+#[test]
+fn defenders() {
+    let board =
+        ChessBoard::from_fen("r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq -")
+            .unwrap();
+    for square in board.occupancy() {
+        let color = board.piece_at(square).unwrap().color;
+        let defenders = board.defenders(square);
+        assert_eq!(defenders, board.attackers_to(square, color));
+        assert_eq!(defenders & board.color_occupancy(!color), BitBoard::EMPTY);
+    }
+
+    // Undefended attacked pieces.
+    let hanging = board
+        .color_occupancy(Color::Black)
+        .into_iter()
+        .filter(|sq| board.is_attacked(*sq, Color::White) && board.defenders(*sq).is_empty())
+        .collect::<Vec<_>>();
+    assert_eq!(hanging, vec![Square::A6]);
+
+    assert_eq!(board.defenders(Square::E3), BitBoard::EMPTY);
+}
+
+#[test]
+fn try_king_square() {
+    let board =
+        ChessBoard::from_fen("r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq -")
+            .unwrap();
+    for mv in MoveGen::legal(&board) {
+        let child = board.get_child(mv);
+        for color in [Color::White, Color::Black] {
+            assert_eq!(
+                child.try_king_square(color),
+                Some(child.get_king_square(color))
+            );
+        }
+    }
+}
+
+#[test]
+#[cfg(feature = "serde")]
+fn try_king_square_missing() {
+    // Valid boards always have both kings, but a deserialized board may not.
+    let board = ChessBoard::from_fen("4k3/8/8/8/8/8/8/4K3 w - -").unwrap();
+    let mut value = serde_json::to_value(&board).unwrap();
+    value["piece_bbs"][PieceType::King.index()]["val"] = Square::E1.bitboard().to_u64().into();
+    value["color_bbs"][Color::Black.index()]["val"] = 0.into();
+
+    let board: ChessBoard = serde_json::from_value(value).unwrap();
+    assert_eq!(board.try_king_square(Color::White), Some(Square::E1));
+    assert_eq!(board.try_king_square(Color::Black), None);
+}
+
+#[test]
+fn apply_san() {
+    // The opera game, Morphy against the Duke of Brunswick and Count Isouard.
+    let san = "e4 e5 Nf3 d6 d4 Bg4 dxe5 Bxf3 Qxf3 dxe5 Bc4 Nf6 Qb3 Qe7 Nc3 c6 Bg5 b5 Nxb5 cxb5 \
+               Bxb5+ Nbd7 O-O-O Rd8 Rxd7 Rxd7 Rd1 Qe6 Bxd7+ Nxd7 Qb8+ Nxb8 Rd8#";
+    let mut board = ChessBoard::new();
+    for mv in san.split_whitespace() {
+        board.apply_san(mv).unwrap();
+    }
+    assert!(board.is_checkmate());
+    assert_eq!(
+        board.get_fen(),
+        "1n1Rkb1r/p4ppp/4q3/4p1B1/4P3/8/PPP2PPP/2K5 b k -"
+    );
+
+    // Promotions, en passant and disambiguation by rank.
+    let board = ChessBoard::from_fen("1n2k3/P7/8/3pP3/8/R7/8/R3K3 w Q d6").unwrap();
+    for (san, uci) in [
+        ("a8=Q", "a7a8q"),
+        ("a8N+", "a7a8n"),
+        ("axb8=R", "a7b8r"),
+        ("exd6", "e5d6"),
+        ("R1a2", "a1a2"),
+        ("R3a2", "a3a2"),
+        ("O-O-O", "e1c1"),
+    ] {
+        let expected = MoveGen::create_str_move(&board, uci).ok();
+        assert_eq!(
+            MoveGen::create_san_move(&board, san).ok(),
+            expected,
+            "{}",
+            san
+        );
+    }
+
+    for san in ["Ra2", "a8", "Kd3", "Qd1", "Zd2", "e", "exd6e"] {
+        assert!(MoveGen::create_san_move(&board, san).is_err(), "{}", san);
+    }
+
+    // Invalid moves leave the board unchanged.
+    let mut board = ChessBoard::new();
+    assert_eq!(
+        board.apply_san("Nf6"),
+        Err(StrMoveCreationError::IllegalMove(MoveCreationError))
+    );
+    assert_eq!(
+        board.apply_uci("e2"),
+        Err(StrMoveCreationError::InvalidMove)
+    );
+    assert_eq!(board, ChessBoard::new());
+}
+
+#[test]
+fn from_fen_lenient() {
+    // Impossible and repeated castling rights are dropped.
+    for (fen, expected) in [
+        ("4k3/8/8/8/8/8/8/4K3 w KQkq -", "4k3/8/8/8/8/8/8/4K3 w - -"),
+        (
+            "r3k2r/8/8/8/8/8/8/R4K1R w KQkq -",
+            "r3k2r/8/8/8/8/8/8/R4K1R w kq -",
+        ),
+        (
+            "r3k3/8/8/8/8/8/8/R3K2R b KQkqkq -",
+            "r3k3/8/8/8/8/8/8/R3K2R b KQq -",
+        ),
+        (
+            "4k2r/8/8/8/8/8/8/4K3 b KQkq -",
+            "4k2r/8/8/8/8/8/8/4K3 b k -",
+        ),
+    ] {
+        assert!(ChessBoard::from_fen(fen).is_err());
+        assert_eq!(
+            ChessBoard::from_fen_lenient(fen).unwrap(),
+            ChessBoard::from_fen(expected).unwrap()
+        );
+    }
+
+    // Each castling right needs its own rook.
+    assert!(ChessBoard::from_fen("4k2r/8/8/8/8/8/8/4K3 b k -").is_ok());
+    assert!(ChessBoard::from_fen("r3k3/8/8/8/8/8/8/4K3 b q -").is_ok());
+    assert!(ChessBoard::from_fen("4k2r/8/8/8/8/8/8/4K3 b q -").is_err());
+
+    // Surrounding whitespace is ignored.
+    assert_eq!(
+        ChessBoard::from_fen("  rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR   w KQkq - 0 1 \n")
+            .unwrap(),
+        ChessBoard::new()
+    );
+
+    // Other errors are still reported.
+    assert!(ChessBoard::from_fen_lenient("4k3/8/8/8/8/8/8/4K3 w KX -").is_err());
+    assert!(ChessBoard::from_fen_lenient("4k3/8/8/8/8/8/8/4K3 w - e3").is_err());
+}
+
+#[test]
+fn castling_rights() {
+    let mut board = ChessBoard::new();
+    assert_eq!(board.castling_rights(), [true; 4]);
+
+    // Moving the kingside rooks drops the kingside rights.
+    for mv in ["h2h4", "h7h5", "h1h3", "h8h6"] {
+        board.apply_uci(mv).unwrap();
+    }
+    assert_eq!(board.castling_rights(), [false, true, false, true]);
+
+    // Moving the kings drops the rest.
+    for mv in ["e2e3", "e7e6", "e1e2", "e8e7"] {
+        board.apply_uci(mv).unwrap();
+    }
+    assert_eq!(board.castling_rights(), [false; 4]);
+}
+
+#[test]
+fn create_move_with_default() {
+    let board = ChessBoard::from_fen("1n5k/P4P2/8/8/8/8/8/4K3 w - -").unwrap();
+    for target in [
+        PieceType::Knight,
+        PieceType::Bishop,
+        PieceType::Rook,
+        PieceType::Queen,
+    ] {
+        assert_eq!(
+            MoveGen::create_move_with_default(&board, Square::A7, Square::B8, target),
+            Ok(Move::PromoteCapture {
+                start: Square::A7,
+                end: Square::B8,
+                target
+            })
+        );
+        assert_eq!(
+            MoveGen::create_move_with_default(&board, Square::E1, Square::D2, target),
+            MoveGen::create_move(&board, Square::E1, Square::D2)
+        );
+    }
+
+    assert_eq!(
+        MoveGen::create_move_with_default(&board, Square::F7, Square::F8, PieceType::King),
+        Err(MoveCreationError)
+    );
+    assert_eq!(
+        MoveGen::create_move_with_default(&board, Square::F7, Square::E8, PieceType::Knight),
+        Err(MoveCreationError)
+    );
+}
+
+#[test]
+fn only_king_moves() {
+    let board =
+        ChessBoard::from_fen("r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq -")
+            .unwrap();
+    for mv in MoveGen::legal(&board) {
+        let child = board.get_child(mv);
+        for mv in MoveGen::legal(&child) {
+            let grandchild = child.get_child(mv);
+            let king = grandchild.get_king_square(grandchild.turn());
+            let only_king = MoveGen::legal(&grandchild).all(|mv| match mv {
+                Move::Quiet { start, .. } | Move::Capture { start, .. } => start == king,
+                _ => false,
+            });
+            if grandchild.only_king_moves() {
+                assert!(only_king);
+            }
+            assert_eq!(
+                grandchild.only_king_moves(),
+                grandchild.checkers().more_than_one()
+            );
+        }
+    }
+
+    // A double check by a rook and a bishop.
+    let board = ChessBoard::from_fen("4k3/8/8/8/1b6/5N2/8/r3K2R w - -").unwrap();
+    assert_eq!(board.checkers().popcnt(), 2);
+    assert!(board.only_king_moves());
+    assert!(MoveGen::legal(&board)
+        .all(|mv| board.get_child(mv).get_king_square(Color::White) != Square::E1));
+
+    // A single check can be blocked.
+    let board = ChessBoard::from_fen("4k3/8/8/8/1b6/5N2/8/4K2R w - -").unwrap();
+    assert!(!board.only_king_moves());
+    assert!(MoveGen::legal(&board).any(|mv| mv.end() == Square::D2));
+}
+
+#[test]
+fn occupancy_after() {
+    for fen in [
+        "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq -",
+        "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R b KQkq -",
+        "4k3/8/8/3pP3/8/8/8/4K3 w - d6",
+        "4k3/8/8/8/3pP3/8/8/4K3 b - e3",
+        "n1n5/PPPk4/8/8/8/8/4Kppp/5N1N b - -",
+    ] {
+        let board = ChessBoard::from_fen(fen).unwrap();
+        for mv in MoveGen::legal(&board) {
+            assert_eq!(board.occupancy_after(mv), board.get_child(mv).occupancy());
+        }
+    }
+}
+
+#[test]
+fn null_move_board() {
+    // The en passant square is cleared, and the half move clock goes up.
+    let board = ChessBoard::from_fen("4k3/8/8/3pP3/8/8/8/4K3 w - d6 0 1").unwrap();
+    let passed = board.null_move_board().unwrap();
+    assert_eq!(
+        passed,
+        ChessBoard::from_fen("4k3/8/8/3pP3/8/8/8/4K3 b - - 1 1").unwrap()
+    );
+    assert_eq!(passed.hash(), passed.recompute_hash());
+
+    // The pins are for the new side to move, and the original board is unchanged.
+    let board = ChessBoard::from_fen("4k3/4r3/8/8/8/8/4B3/4K2R b - - 3 1").unwrap();
+    let passed = board.null_move_board().unwrap();
+    assert_eq!(passed.pinned(), Square::E2.bitboard());
+    assert_eq!(board.pinned(), BitBoard::EMPTY);
+    assert_eq!(
+        passed.null_move_board(),
+        ChessBoard::from_fen("4k3/4r3/8/8/8/8/4B3/4K2R b - - 5 1").ok()
+    );
+
+    // Passing out of check is not possible.
+    let board = ChessBoard::from_fen("4k3/8/8/8/8/8/8/r3K3 w - -").unwrap();
+    assert_eq!(board.null_move_board(), None);
+}
+
+#[test]
+fn attacked_squares() {
+    for fen in [
+        "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq -",
+        "8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - -",
+        "r4rk1/1pp1qppp/p1np1n2/2b1p1B1/2B1P1b1/P1NP1N2/1PP1QPPP/R4RK1 w - -",
+    ] {
+        let board = ChessBoard::from_fen(fen).unwrap();
+        for color in [Color::White, Color::Black] {
+            // The 3x3 zone around the king.
+            let king = board.get_king_square(color);
+            let zone = BitBoard::from_squares(&king.king_neighbors().collect::<Vec<_>>())
+                | king.bitboard();
+
+            let expected = zone.filter(|sq| board.is_attacked(*sq, !color));
+            assert_eq!(
+                board.attacked_squares(zone, !color),
+                BitBoard::from_squares(&expected.collect::<Vec<_>>())
+            );
+        }
+    }
+}
+
+#[test]
+fn imbalance() {
+    let balanced = Imbalance::default();
+    for (fen, expected) in [
+        (
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq -",
+            balanced,
+        ),
+        (
+            "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq -",
+            balanced,
+        ),
+        (
+            "rnb1kbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq -",
+            Imbalance {
+                queen: 1,
+                ..balanced
+            },
+        ),
+        (
+            "r1bqkbnr/pppppppp/8/8/8/8/PPPPPPPP/R1BQKB1R w KQkq -",
+            Imbalance {
+                knight: -1,
+                ..balanced
+            },
+        ),
+        (
+            "2b1k3/pppp4/8/8/8/8/PP6/4KR2 w - -",
+            Imbalance {
+                pawn: -2,
+                bishop: -1,
+                rook: 1,
+                ..balanced
+            },
+        ),
+        (
+            "NNNNNNNN/NNNNNNNN/N7/8/8/8/8/K6k w - -",
+            Imbalance {
+                knight: 17,
+                ..balanced
+            },
+        ),
+    ] {
+        let board = ChessBoard::from_fen(fen).unwrap();
+        let imbalance = board.imbalance();
+        assert_eq!(imbalance, expected, "{}", fen);
+        assert_eq!(imbalance.is_balanced(), expected == balanced, "{}", fen);
+
+        // The imbalance matches the difference in material.
+        let values = PieceValues::DEFAULT;
+        let difference = [
+            PieceType::Pawn,
+            PieceType::Knight,
+            PieceType::Bishop,
+            PieceType::Rook,
+            PieceType::Queen,
+        ]
+        .map(|kind| imbalance.count(kind) as i32 * values.value(kind))
+        .iter()
+        .sum::<i32>();
+        assert_eq!(
+            difference,
+            board.material_with(&values, Color::White) - board.material_with(&values, Color::Black)
+        );
+    }
+
+    let board = ChessBoard::from_fen("NNNNNNNN/NNNNNNNN/N7/8/8/8/8/K6k w - -").unwrap();
+    assert_eq!(board.imbalance().to_string(), "+17 knights");
+}
@@ -0,0 +1,107 @@
+use rchess::uci::{self, UciError};
+use rchess::{ChessBoard, MoveGen, StrMoveCreationError};
+
+#[test]
+fn startpos() {
+    let board = uci::parse_position("position startpos").unwrap();
+    assert_eq!(board, ChessBoard::new());
+}
+
+#[test]
+fn startpos_moves() {
+    let board = uci::parse_position("position startpos moves e2e4 c7c5 g1f3").unwrap();
+    let expected =
+        ChessBoard::from_fen("rnbqkbnr/pp1ppppp/8/2p5/4P3/5N2/PPPP1PPP/RNBQKB1R b KQkq - 1 2")
+            .unwrap();
+    assert_eq!(board, expected);
+}
+
+#[test]
+fn fen() {
+    let fen = "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1";
+    let board = uci::parse_position(&format!("position fen {}", fen)).unwrap();
+    assert_eq!(board, ChessBoard::from_fen(fen).unwrap());
+}
+
+#[test]
+fn fen_moves() {
+    let fen = "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1";
+    let board = uci::parse_position(&format!("position fen {} moves e1g1 e8c8", fen)).unwrap();
+    let expected =
+        ChessBoard::from_fen("2kr3r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R4RK1 w - - 2 2")
+            .unwrap();
+    assert_eq!(board, expected);
+}
+
+#[test]
+fn invalid_commands() {
+    assert_eq!(
+        uci::parse_position("go depth 5"),
+        Err(UciError::NotPosition)
+    );
+    assert_eq!(
+        uci::parse_position("position kiwipete"),
+        Err(UciError::InvalidPosition)
+    );
+    assert_eq!(
+        uci::parse_position("position startpos e2e4"),
+        Err(UciError::InvalidPosition)
+    );
+    assert!(matches!(
+        uci::parse_position("position fen 8/8/8 w - - 0 1"),
+        Err(UciError::Fen(_))
+    ));
+    assert!(matches!(
+        uci::parse_position("position startpos moves e2e5"),
+        Err(UciError::Move(StrMoveCreationError::IllegalMove(_)))
+    ));
+}
+
+#[test]
+fn format_moves() {
+    let board = uci::parse_position("position fen 1r5k/P7/8/8/8/8/8/K7 w - - 0 1").unwrap();
+    for mv in MoveGen::legal(&board) {
+        let str = uci::format_move(mv);
+        assert_eq!(str, str.to_lowercase());
+        assert_eq!(MoveGen::create_str_move(&board, &str), Ok(mv));
+    }
+
+    let mv = MoveGen::create_str_move(&board, "a7b8r").unwrap();
+    assert_eq!(uci::format_move(mv), "a7b8r");
+}
+
+#[test]
+fn to_uci() {
+    let board = uci::parse_position("position fen r3k3/8/8/8/8/7K/1p6/R7 b q - 0 1").unwrap();
+    for mv in MoveGen::legal(&board) {
+        let str = mv.to_uci();
+        assert_eq!(str, uci::format_move(mv));
+        assert_eq!(MoveGen::create_str_move(&board, &str), Ok(mv));
+    }
+
+    let castle = MoveGen::create_str_move(&board, "e8c8").unwrap();
+    assert_eq!(castle.to_uci(), "e8c8");
+    let promotion = MoveGen::create_str_move(&board, "b2a1r").unwrap();
+    assert_eq!(promotion.to_uci(), "b2a1r");
+}
+
+#[test]
+fn chess_960_castling() {
+    let board = uci::parse_position("position fen r3k2r/8/8/8/8/8/8/R3K2R b KQkq - 0 1").unwrap();
+    for (str, standard) in [
+        ("e8h8", "e8g8"),
+        ("e8a8", "e8c8"),
+        ("e8g8", "e8g8"),
+        ("a8a1", "a8a1"),
+    ] {
+        assert_eq!(
+            MoveGen::create_uci_move_960(&board, str),
+            MoveGen::create_str_move(&board, standard)
+        );
+    }
+
+    // The king cannot castle onto a rook when the castling right is gone.
+    let board = uci::parse_position("position fen r3k2r/8/8/8/8/8/8/R3K2R w Qkq - 0 1").unwrap();
+    assert!(MoveGen::create_uci_move_960(&board, "e1h1").is_err());
+    assert!(MoveGen::create_uci_move_960(&board, "e1a1").is_ok());
+}